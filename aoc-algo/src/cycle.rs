@@ -0,0 +1,197 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states of a simulation start repeating: the state after `start` steps comes back
+/// every `length` steps from then on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step whose state is the same as the state after `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Steps from `initial` until a state comes back, and returns the cycle with that state.
+///
+/// `key` tells states apart: two states with the same key count as the same state. It can be
+/// the state itself, cloned, or something smaller that still identifies it, like the positions
+/// of the rocks that move on a grid that mostly does not; every key is kept until the cycle is
+/// found. The states must repeat eventually, as they do when there are only finitely many.
+pub fn find_cycle<S, K: Eq + Hash>(
+    initial: S,
+    step: impl FnMut(S) -> S,
+    key: impl FnMut(&S) -> K,
+) -> (Cycle, S) {
+    let (state, Some(cycle)) = walk(initial, None, step, key) else {
+        unreachable!("only a repeated state ends a walk without a limit");
+    };
+    (cycle, state)
+}
+
+/// The state after `n` steps from `initial`, like a billion spins of a platform, without taking
+/// all of them: once a state comes back, the whole cycles left over are skipped. `key` is as
+/// for [`find_cycle`].
+pub fn state_after<S, K: Eq + Hash>(
+    initial: S,
+    n: usize,
+    mut step: impl FnMut(S) -> S,
+    key: impl FnMut(&S) -> K,
+) -> S {
+    match walk(initial, Some(n), &mut step, key) {
+        (state, None) => state,
+        (state, Some(cycle)) => {
+            let done = cycle.start + cycle.length;
+            (0..(n - done) % cycle.length).fold(state, |state, _| step(state))
+        }
+    }
+}
+
+/// Steps until a state comes back or `limit` steps are taken, whichever is first, and returns
+/// the state then, with the cycle if one was found.
+fn walk<S, K: Eq + Hash>(
+    initial: S,
+    limit: Option<usize>,
+    mut step: impl FnMut(S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> (S, Option<Cycle>) {
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    let mut steps = 0;
+
+    while limit != Some(steps) {
+        match seen.entry(key(&state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                let cycle = Cycle {
+                    start,
+                    length: steps - start,
+                };
+                return (state, Some(cycle));
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(steps);
+            }
+        }
+        state = step(state);
+        steps += 1;
+    }
+    (state, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 → 1 → 2 → 3 → 4 → 5 → 2 → …: a tail of two steps into a cycle of four.
+    fn rho(x: u32) -> u32 {
+        if x == 5 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    fn brute_force(initial: u32, n: usize, step: impl Fn(u32) -> u32) -> u32 {
+        (0..n).fold(initial, |x, _| step(x))
+    }
+
+    #[test]
+    fn finds_the_tail_and_length() {
+        assert_eq!(
+            (
+                Cycle {
+                    start: 2,
+                    length: 4
+                },
+                2
+            ),
+            find_cycle(0, rho, |&x| x)
+        );
+        assert_eq!(
+            (
+                Cycle {
+                    start: 0,
+                    length: 4
+                },
+                3
+            ),
+            find_cycle(3, rho, |&x| x)
+        );
+        assert_eq!(
+            (
+                Cycle {
+                    start: 0,
+                    length: 1
+                },
+                7
+            ),
+            find_cycle(7, |x| x, |&x| x)
+        );
+    }
+
+    #[test]
+    fn reduces_steps_into_the_first_cycle() {
+        let cycle = Cycle {
+            start: 2,
+            length: 4,
+        };
+        assert_eq!(1, cycle.reduce(1));
+        assert_eq!(5, cycle.reduce(5));
+        assert_eq!(2, cycle.reduce(6));
+        assert_eq!(4, cycle.reduce(1_000_000_000));
+    }
+
+    #[test]
+    fn agrees_with_stepping_every_time() {
+        // Squaring modulo a number wanders for a while before it falls into a cycle.
+        let square = |x: u32| (x * x + 1) % 1009;
+        for initial in [0, 3, 500] {
+            for n in 0..300 {
+                assert_eq!(
+                    brute_force(initial, n, square),
+                    state_after(initial, n, square, |&x| x),
+                    "{n} steps from {initial}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn skips_whole_cycles() {
+        let n = 1_000_000_000;
+        let mut steps = 0;
+        let state = state_after(
+            0,
+            n,
+            |x| {
+                steps += 1;
+                rho(x)
+            },
+            |&x| x,
+        );
+        assert_eq!(brute_force(0, n % 4 + 4, rho), state);
+        assert!(steps < 10, "took {steps} steps");
+    }
+
+    #[test]
+    fn tells_large_states_apart_by_their_key() {
+        // Only the first value says anything about the state, so only it is kept.
+        let step = |state: Vec<u32>| state.into_iter().map(rho).collect::<Vec<_>>();
+        let initial = vec![0; 1000];
+        let state = state_after(initial, 1_000_000_001, step, |state| state[0]);
+        assert_eq!(
+            vec![brute_force(0, 1_000_000_001 % 4 + 4, rho); 1000],
+            state
+        );
+    }
+}
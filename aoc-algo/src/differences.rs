@@ -0,0 +1,64 @@
+use num::{CheckedAdd, CheckedSub, Zero};
+
+/// The rows of differences of `sequence`: the sequence itself, the differences between its
+/// neighbours, their differences and so on, down to a row of zeros. A sequence no polynomial
+/// fits ends in an empty row instead. `None` if a difference does not fit in `T`.
+pub fn difference_table<T: CheckedSub + Zero + Copy>(sequence: &[T]) -> Option<Vec<Vec<T>>> {
+    let mut rows = vec![sequence.to_vec()];
+
+    while let Some(last) = rows.last().filter(|row| row.iter().any(|x| !x.is_zero())) {
+        let next = last
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(&pair[0]))
+            .collect::<Option<Vec<_>>>()?;
+        rows.push(next);
+    }
+    Some(rows)
+}
+
+/// The value that would follow `sequence`, extrapolated from its differences.
+pub fn next_value<T: CheckedAdd + CheckedSub + Zero + Copy>(sequence: &[T]) -> Option<T> {
+    difference_table(sequence)?
+        .iter()
+        .filter_map(|row| row.last())
+        .try_fold(T::zero(), |sum, x| sum.checked_add(x))
+}
+
+/// The value that would come before `sequence`, extrapolated from its differences.
+pub fn previous_value<T: CheckedSub + Zero + Copy>(sequence: &[T]) -> Option<T> {
+    difference_table(sequence)?
+        .iter()
+        .rev()
+        .filter_map(|row| row.first())
+        .try_fold(T::zero(), |below, x| x.checked_sub(&below))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_the_table_down_to_zeros() {
+        assert_eq!(
+            Some(vec![vec![1, 3, 6, 10], vec![2, 3, 4], vec![1, 1], vec![0]]),
+            difference_table(&[1, 3, 6, 10])
+        );
+        assert_eq!(Some(vec![vec![0, 0]]), difference_table(&[0, 0]));
+        assert_eq!(Some(vec![vec![5], vec![]]), difference_table(&[5]));
+    }
+
+    #[test]
+    fn extrapolates_polynomials() {
+        // n² + 1
+        let squares = [2i64, 5, 10, 17, 26];
+        assert_eq!(Some(37), next_value(&squares));
+        assert_eq!(Some(1), previous_value(&squares));
+        assert_eq!(Some(0), next_value::<i32>(&[]));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        assert_eq!(None, difference_table(&[i32::MAX, i32::MIN]));
+        assert_eq!(None, next_value(&[u8::MAX - 1, u8::MAX]));
+    }
+}
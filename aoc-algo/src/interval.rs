@@ -0,0 +1,248 @@
+use std::ops::Range;
+
+/// A set of values stored as the half-open ranges that cover them, for sets too large to list
+/// one by one, like the seeds of day 5.
+///
+/// The ranges are kept sorted, non-empty and coalesced: no two of them overlap or touch, so
+/// equal sets always hold the same ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    /// The empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges that make up the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let after = self.ranges.partition_point(|range| range.start <= value);
+        after
+            .checked_sub(1)
+            .is_some_and(|i| value < self.ranges[i].end)
+    }
+
+    /// Adds every value in `range`.
+    pub fn insert(&mut self, range: Range<T>) {
+        self.ranges.push(range);
+        self.ranges = coalesce(std::mem::take(&mut self.ranges));
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let overlap = x.start.max(y.start)..x.end.min(y.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Whichever ends first cannot overlap anything further along the other set.
+            if x.end <= y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut removed = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(hole) = removed.peek() {
+                if hole.end <= start {
+                    removed.next();
+                    continue;
+                }
+                if hole.start >= range.end {
+                    break;
+                }
+                if hole.start > start {
+                    ranges.push(start..hole.start);
+                }
+                start = hole.end;
+                if hole.end > range.end {
+                    break;
+                }
+                removed.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The set's ranges cut wherever one of `boundaries` falls inside them, so that no piece
+    /// has a boundary past its start. Unlike the set itself, the pieces may touch.
+    pub fn split(&self, boundaries: impl IntoIterator<Item = T>) -> Vec<Range<T>> {
+        let mut boundaries = boundaries.into_iter().collect::<Vec<_>>();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let mut pieces = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            let first = boundaries.partition_point(|&boundary| boundary <= start);
+            for &boundary in boundaries[first..].iter().take_while(|&&b| b < range.end) {
+                pieces.push(start..boundary);
+                start = boundary;
+            }
+            pieces.push(start..range.end);
+        }
+        pieces
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+/// Collects any ranges, overlapping, touching, unsorted or empty, into the set of their values.
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        Self {
+            ranges: coalesce(ranges.into_iter().collect()),
+        }
+    }
+}
+
+/// `ranges` sorted, without the empty ones, and with those that overlap or touch merged.
+fn coalesce<T: Copy + Ord>(mut ranges: Vec<Range<T>>) -> Vec<Range<T>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<u64>]) -> IntervalSet<u64> {
+        ranges.iter().cloned().collect()
+    }
+
+    /// The set of every value below 64 that is in `set`, one bit each, to check the ranges
+    /// against.
+    fn bits(set: &IntervalSet<u64>) -> u64 {
+        (0..64)
+            .filter(|&value| set.contains(value))
+            .fold(0, |bits, value| bits | 1 << value)
+    }
+
+    /// Some sets of small values with ranges that overlap each other in every way.
+    fn samples() -> Vec<IntervalSet<u64>> {
+        vec![
+            set(&[]),
+            IntervalSet::from(0..64),
+            IntervalSet::from(3..9),
+            set(&[0..4, 9..12, 20..21]),
+            set(&[2..10, 11..30, 40..50]),
+            set(&[5..6, 8..9, 12..25, 48..64]),
+        ]
+    }
+
+    #[test]
+    fn coalesces_overlapping_and_touching_ranges() {
+        let set = set(&[10..20, 3..5, 15..25, 5..7, 30..30, 40..41]);
+        assert_eq!(&[3..7, 10..25, 40..41], set.ranges());
+        assert_eq!(Some(3), set.min());
+        assert!(set.contains(24));
+        assert!(!set.contains(25));
+        assert!(!set.contains(2));
+    }
+
+    #[test]
+    fn inserts_ranges() {
+        let mut set = IntervalSet::new();
+        assert!(set.is_empty());
+        set.insert(10..20);
+        set.insert(0..5);
+        set.insert(5..10);
+        assert_eq!(IntervalSet::from(0..20), set);
+    }
+
+    #[test]
+    fn agrees_with_sets_of_single_values() {
+        for a in samples() {
+            for b in samples() {
+                assert_eq!(bits(&a) | bits(&b), bits(&a.union(&b)), "{a:?} ∪ {b:?}");
+                assert_eq!(
+                    bits(&a) & bits(&b),
+                    bits(&a.intersection(&b)),
+                    "{a:?} ∩ {b:?}"
+                );
+                assert_eq!(
+                    bits(&a) & !bits(&b),
+                    bits(&a.difference(&b)),
+                    "{a:?} - {b:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn results_stay_coalesced() {
+        let a = set(&[0..10, 20..30]);
+        let b = IntervalSet::from(10..20);
+        let c = IntervalSet::from(5..25);
+        assert_eq!(IntervalSet::from(0..30), a.union(&b));
+        assert_eq!(&[0..5, 25..30], a.difference(&c).ranges());
+        assert_eq!(&[5..10, 20..25], a.intersection(&c).ranges());
+    }
+
+    #[test]
+    fn splits_at_boundaries_inside_ranges() {
+        let set = set(&[0..10, 20..30]);
+        assert_eq!(
+            vec![0..3, 3..10, 20..25, 25..30],
+            set.split([25, 3, 10, 3, 40])
+        );
+        assert_eq!(vec![0..10, 20..30], set.split([]));
+    }
+}
@@ -0,0 +1,22 @@
+//! General algorithms lifted out of the day crates, for puzzles that need them again.
+//!
+//! Most of it is generic over its numbers and states; the polygon measures take `i64` vertices.
+//! Sums and products of values from the input are checked and report overflow as `None` instead
+//! of panicking, in line with how the solvers treat malformed input, except where a function
+//! leaves the range to its caller, as `RangeMap::get` does.
+
+mod cycle;
+mod differences;
+mod interval;
+mod math;
+mod path;
+mod polygon;
+mod range_map;
+
+pub use cycle::{find_cycle, state_after, Cycle};
+pub use differences::{difference_table, next_value, previous_value};
+pub use interval::IntervalSet;
+pub use math::{lcm, lcm_all};
+pub use path::dijkstra;
+pub use polygon::{boundary_points, double_area, enclosed_points};
+pub use range_map::RangeMap;
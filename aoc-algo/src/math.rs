@@ -0,0 +1,34 @@
+use num::{CheckedMul, Integer};
+
+/// The least common multiple of two non-negative numbers, or `None` if it does not fit in `T`.
+pub fn lcm<T: Integer + CheckedMul + Copy>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
+    }
+    (a / a.gcd(&b)).checked_mul(&b)
+}
+
+/// The least common multiple of all `values`, e.g. the first time several cycles line up again.
+/// One for no values at all.
+pub fn lcm_all<T: Integer + CheckedMul + Copy>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::one(), lcm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_common_multiples() {
+        assert_eq!(Some(12), lcm(4u64, 6));
+        assert_eq!(Some(0), lcm(0u64, 6));
+        assert_eq!(Some(60), lcm_all([3u32, 4, 5, 6]));
+        assert_eq!(Some(1), lcm_all(Vec::<u8>::new()));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        assert_eq!(None, lcm(200u8, 3));
+        assert_eq!(None, lcm_all([u64::MAX, u64::MAX - 1]));
+    }
+}
@@ -0,0 +1,166 @@
+use num::CheckedAdd;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// A node reached so far, with the cheapest known way there.
+struct Reached<N, C> {
+    node: N,
+    cost: C,
+    parent: Option<usize>,
+}
+
+/// The cheapest path from one of `starts` to a node that satisfies `success`, with its cost.
+///
+/// `successors` lists the nodes one step away from a node together with what the step costs,
+/// which must not be negative. Nodes can carry whatever state limits the next steps, such as
+/// the heading and straight run of a crucible on day 17, so the graph never has to be built up
+/// front. The path starts with the start node it was found from and ends with the goal.
+///
+/// `None` if no goal can be reached, or if adding up the costs on the way overflows `C`.
+pub fn dijkstra<N, C, S, I>(
+    starts: impl IntoIterator<Item = (N, C)>,
+    mut successors: S,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + CheckedAdd,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached = Vec::<Reached<N, C>>::new();
+    let mut index = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for (node, cost) in starts {
+        relax(&mut reached, &mut index, &mut queue, node, cost, None);
+    }
+
+    while let Some(Reverse((cost, at))) = queue.pop() {
+        // A cheaper way here was found after this one was queued.
+        if cost > reached[at].cost {
+            continue;
+        }
+        if success(&reached[at].node) {
+            return Some((path(&reached, at), cost));
+        }
+
+        for (next, step) in successors(&reached[at].node) {
+            let cost = cost.checked_add(&step)?;
+            relax(&mut reached, &mut index, &mut queue, next, cost, Some(at));
+        }
+    }
+    None
+}
+
+/// Records that `node` can be reached for `cost`, if that is the first or a cheaper way there.
+fn relax<N: Eq + Hash + Clone, C: Ord + Copy>(
+    reached: &mut Vec<Reached<N, C>>,
+    index: &mut HashMap<N, usize>,
+    queue: &mut BinaryHeap<Reverse<(C, usize)>>,
+    node: N,
+    cost: C,
+    parent: Option<usize>,
+) {
+    match index.entry(node) {
+        Entry::Vacant(entry) => {
+            reached.push(Reached {
+                node: entry.key().clone(),
+                cost,
+                parent,
+            });
+            entry.insert(reached.len() - 1);
+            queue.push(Reverse((cost, reached.len() - 1)));
+        }
+        Entry::Occupied(entry) => {
+            let known = &mut reached[*entry.get()];
+            if cost < known.cost {
+                known.cost = cost;
+                known.parent = parent;
+                queue.push(Reverse((cost, *entry.get())));
+            }
+        }
+    }
+}
+
+fn path<N: Clone, C>(reached: &[Reached<N, C>], goal: usize) -> Vec<N> {
+    let mut path = Vec::new();
+    let mut at = Some(goal);
+    while let Some(i) = at {
+        path.push(reached[i].node.clone());
+        at = reached[i].parent;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Edges of a small graph, from node, to node, cost.
+    const EDGES: [(u8, u8, u32); 6] = [
+        (0, 1, 7),
+        (0, 2, 2),
+        (2, 1, 3),
+        (1, 3, 1),
+        (2, 3, 9),
+        (3, 4, 4),
+    ];
+
+    fn neighbours(node: &u8) -> Vec<(u8, u32)> {
+        EDGES
+            .iter()
+            .filter(|(from, _, _)| from == node)
+            .map(|&(_, to, cost)| (to, cost))
+            .collect()
+    }
+
+    #[test]
+    fn finds_the_cheapest_path() {
+        assert_eq!(
+            Some((vec![0, 2, 1, 3, 4], 10)),
+            dijkstra([(0, 0)], neighbours, |&node| node == 4)
+        );
+    }
+
+    #[test]
+    fn starts_anywhere_with_a_head_start() {
+        assert_eq!(
+            Some((vec![3, 4], 5)),
+            dijkstra([(0, 0), (3, 1)], neighbours, |&node| node == 4)
+        );
+        assert_eq!(
+            Some((vec![0], 0)),
+            dijkstra([(0, 0)], neighbours, |&node| node == 0)
+        );
+    }
+
+    #[test]
+    fn reports_unreachable_goals() {
+        assert_eq!(None, dijkstra([(4, 0)], neighbours, |&node| node == 0));
+    }
+
+    #[test]
+    fn reports_costs_that_overflow() {
+        let successors = |&node: &u8| [(node + 1, u8::MAX / 2)];
+        assert_eq!(None, dijkstra([(0, 0u8)], successors, |&node| node == 3));
+    }
+
+    #[test]
+    fn keeps_state_in_the_nodes() {
+        // Walks right along a line, at most two steps in a row before it has to pause.
+        let successors = |&(x, run): &(u32, u8)| {
+            let mut next = vec![((x, 0), 5)];
+            if run < 2 {
+                next.push(((x + 1, run + 1), 1));
+            }
+            next
+        };
+        let (path, cost) = dijkstra([((0, 0), 0)], successors, |&(x, _)| x == 4).unwrap();
+        assert_eq!(4 + 5, cost);
+        assert_eq!(Some(&(4, 2)), path.last());
+    }
+}
@@ -0,0 +1,78 @@
+//! Areas of polygons with integer vertices, like the trench of a dig plan.
+
+/// Twice the area of the polygon through `vertices`, by the shoelace formula; twice so that it
+/// stays a whole number. The polygon closes from the last vertex back to the first, and it may
+/// run either way round. `None` if the result does not fit in a `u128`.
+pub fn double_area(vertices: &[(i64, i64)]) -> Option<u128> {
+    edges(vertices)
+        .try_fold(0i128, |sum, ((x1, y1), (x2, y2))| {
+            let cross =
+                (i128::from(x1) * i128::from(y2)).checked_sub(i128::from(y1) * i128::from(x2))?;
+            sum.checked_add(cross)
+        })
+        .map(i128::unsigned_abs)
+}
+
+/// How many points with integer coordinates lie on the edges of the polygon through `vertices`.
+pub fn boundary_points(vertices: &[(i64, i64)]) -> Option<u128> {
+    edges(vertices).try_fold(0u128, |sum, ((x1, y1), (x2, y2))| {
+        sum.checked_add(u128::from(num::integer::gcd(
+            x1.abs_diff(x2),
+            y1.abs_diff(y2),
+        )))
+    })
+}
+
+/// How many points with integer coordinates lie inside or on the polygon through `vertices`,
+/// by Pick's theorem. On a grid, that is the cells a trench along the vertices and its inside
+/// cover.
+pub fn enclosed_points(vertices: &[(i64, i64)]) -> Option<u128> {
+    if vertices.is_empty() {
+        return Some(0);
+    }
+    let twice = double_area(vertices)?.checked_add(boundary_points(vertices)?)?;
+    Some(twice / 2 + 1)
+}
+
+fn edges(vertices: &[(i64, i64)]) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&from, &to)| (from, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQUARE: [(i64, i64); 4] = [(0, 0), (2, 0), (2, 2), (0, 2)];
+
+    #[test]
+    fn measures_a_square() {
+        assert_eq!(Some(8), double_area(&SQUARE));
+        assert_eq!(Some(8), boundary_points(&SQUARE));
+        assert_eq!(Some(9), enclosed_points(&SQUARE));
+    }
+
+    #[test]
+    fn ignores_the_winding_direction() {
+        let mut reversed = SQUARE;
+        reversed.reverse();
+        assert_eq!(double_area(&SQUARE), double_area(&reversed));
+    }
+
+    #[test]
+    fn counts_lattice_points_on_diagonals() {
+        let triangle = [(0, 0), (4, 0), (0, 4)];
+        assert_eq!(Some(16), double_area(&triangle));
+        assert_eq!(Some(12), boundary_points(&triangle));
+        assert_eq!(Some(15), enclosed_points(&triangle));
+    }
+
+    #[test]
+    fn handles_degenerate_polygons() {
+        assert_eq!(Some(0), enclosed_points(&[]));
+        assert_eq!(Some(1), enclosed_points(&[(3, 3)]));
+        assert_eq!(Some(5), enclosed_points(&[(0, 0), (4, 0)]));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::IntervalSet;
+use std::ops::{Add, Range, Sub};
+
+/// A piecewise mapping that shifts keys in each of its ranges onto a destination range of the
+/// same length, and leaves keys outside all of them as they are, like the almanac maps of day 5.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    /// Source ranges with the destination of their start, sorted by source.
+    entries: Vec<(Range<T>, T)>,
+}
+
+impl<T> RangeMap<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    /// A map that sends `source.start + i` to `destination + i` for every entry. The sources
+    /// must not overlap.
+    pub fn new(entries: impl IntoIterator<Item = (Range<T>, T)>) -> Self {
+        let mut entries = entries
+            .into_iter()
+            .filter(|(source, _)| !source.is_empty())
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|(source, _)| source.start);
+        Self { entries }
+    }
+
+    /// Where `key` ends up. The caller makes sure destinations fit in `T`, as the sources do.
+    pub fn get(&self, key: T) -> T {
+        let after = self
+            .entries
+            .partition_point(|(source, _)| source.start <= key);
+
+        match after.checked_sub(1).map(|i| &self.entries[i]) {
+            Some((source, destination)) if key < source.end => *destination + (key - source.start),
+            _ => key,
+        }
+    }
+
+    /// Where all of `keys` end up, a whole range at a time: the keys are cut where the source
+    /// ranges start and end, so that every piece moves as one.
+    pub fn image(&self, keys: &IntervalSet<T>) -> IntervalSet<T> {
+        let boundaries = self
+            .entries
+            .iter()
+            .flat_map(|(source, _)| [source.start, source.end]);
+
+        keys.split(boundaries)
+            .into_iter()
+            .map(|piece| {
+                let start = self.get(piece.start);
+                start..start + (piece.end - piece.start)
+            })
+            .collect()
+    }
+
+    /// The source ranges with the destination of their start, ordered by source.
+    pub fn entries(&self) -> &[(Range<T>, T)] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shifts_keys_inside_ranges() {
+        let map = RangeMap::new([(98..100, 50u64), (50..98, 52)]);
+        assert_eq!(81, map.get(79));
+        assert_eq!(50, map.get(98));
+        assert_eq!(51, map.get(99));
+    }
+
+    #[test]
+    fn keeps_keys_outside_ranges() {
+        let map = RangeMap::new([(98..100, 50u64), (50..98, 52)]);
+        assert_eq!(14, map.get(14));
+        assert_eq!(100, map.get(100));
+        assert_eq!(0, map.get(0));
+    }
+
+    #[test]
+    fn moves_whole_ranges() {
+        let map = RangeMap::new([(98..100, 50u64), (50..98, 52)]);
+        let seeds = [79..93, 55..68].into_iter().collect::<IntervalSet<_>>();
+        assert_eq!(&[57..70, 81..95], map.image(&seeds).ranges());
+
+        let straddling = [45..100, 120..121].into_iter().collect::<IntervalSet<_>>();
+        // 45..50 stays, 50..98 moves up by two and 98..100 fills the gap it leaves.
+        assert_eq!(&[45..100, 120..121], map.image(&straddling).ranges());
+        assert_eq!(
+            (45..100).chain(120..121).map(|key| map.get(key)).min(),
+            map.image(&straddling).min()
+        );
+    }
+
+    #[test]
+    fn range_ends_are_exclusive() {
+        let map = RangeMap::new([(10..20, 100i32), (30..40, -5)]);
+        assert_eq!(109, map.get(19));
+        assert_eq!(20, map.get(20));
+        assert_eq!(-5, map.get(30));
+        assert_eq!(40, map.get(40));
+    }
+}
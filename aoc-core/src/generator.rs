@@ -0,0 +1,16 @@
+use crate::{Rng, Solution};
+
+/// Produces random inputs for a day's puzzle, for stress tests and benchmarks beyond the
+/// official input.
+///
+/// Generated inputs must parse and keep the invariants the puzzle text promises, e.g. a
+/// single loop or non-overlapping ranges. What `size` scales is up to each day: lines,
+/// grid width, patterns and so on.
+pub trait Generator: Solution {
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// The input `G` generates for `size` from `seed`, the same on every run.
+pub fn generate<G: Generator>(size: usize, seed: u64) -> String {
+    G::generate(size, &mut Rng::new(seed))
+}
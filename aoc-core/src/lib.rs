@@ -0,0 +1,12 @@
+mod generator;
+pub mod par;
+mod parse;
+mod part;
+mod rng;
+mod solution;
+
+pub use generator::{generate, Generator};
+pub use parse::{check_chars, parse_complete, ParseError, Span};
+pub use part::Part;
+pub use rng::{Rng, Uniform};
+pub use solution::{run, Answer, Solution};
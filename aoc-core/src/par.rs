@@ -0,0 +1,50 @@
+//! Parallel iteration with rayon, or plain iteration without the `rayon` feature, e.g. for
+//! `wasm32-unknown-unknown` where there are no threads to spread the work over.
+//!
+//! Solvers import `aoc_core::par::prelude::*` and only use adaptors that rayon's parallel
+//! iterators and `Iterator` share, such as `map`, `sum` and `min`.
+
+#[cfg(feature = "rayon")]
+pub use rayon::prelude;
+
+#[cfg(not(feature = "rayon"))]
+pub mod prelude {
+    /// Sequential stand-in for `rayon::iter::IntoParallelIterator`.
+    pub trait IntoParallelIterator: IntoIterator + Sized {
+        fn into_par_iter(self) -> Self::IntoIter {
+            self.into_iter()
+        }
+    }
+
+    impl<I: IntoIterator> IntoParallelIterator for I {}
+
+    /// Sequential stand-in for `rayon::iter::IntoParallelRefIterator`.
+    pub trait IntoParallelRefIterator<'a> {
+        type Iter: Iterator;
+
+        fn par_iter(&'a self) -> Self::Iter;
+    }
+
+    impl<'a, C: ?Sized + 'a> IntoParallelRefIterator<'a> for C
+    where
+        &'a C: IntoIterator,
+    {
+        type Iter = <&'a C as IntoIterator>::IntoIter;
+
+        fn par_iter(&'a self) -> Self::Iter {
+            self.into_iter()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+
+    #[test]
+    fn shared_adaptors_work_either_way() {
+        let numbers = vec![3, 1, 2];
+        assert_eq!(12, numbers.par_iter().map(|n| n * 2).sum::<i32>());
+        assert_eq!(Some(1), numbers.into_par_iter().min());
+    }
+}
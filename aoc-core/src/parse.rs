@@ -0,0 +1,170 @@
+use miette::{Diagnostic, SourceSpan};
+use nom::character::complete::multispace0;
+use nom::combinator::all_consuming;
+use nom::error::ErrorKind;
+use nom::sequence::terminated;
+use nom::IResult;
+use nom_locate::LocatedSpan;
+use thiserror::Error;
+
+/// Puzzle input that remembers where in the original text it starts.
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+/// Malformed puzzle input, reported with the offending text highlighted.
+#[derive(Error, Diagnostic, Debug)]
+#[error("{message}")]
+#[diagnostic(code(aoc::parse_error))]
+pub struct ParseError {
+    message: String,
+    #[source_code]
+    input: String,
+    #[label("{label}")]
+    span: SourceSpan,
+    label: String,
+}
+
+impl ParseError {
+    /// An error covering `len` bytes of `input` starting at `offset`.
+    pub fn new(input: &str, offset: usize, len: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+
+        Self {
+            message: message.into(),
+            input: input.to_owned(),
+            span: (offset, len.min(input.len() - offset)).into(),
+            label: "here".to_owned(),
+        }
+    }
+
+    /// An error covering `fragment`, which has to be a slice of `input`; any other string points
+    /// at the start of the input instead.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+
+        match offset <= input.len() && offset + fragment.len() <= input.len() {
+            true => Self::new(input, offset, fragment.len(), message),
+            false => Self::new(input, 0, 0, message),
+        }
+    }
+
+    /// An error just past the last character, for input that stops too early.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::new(input, input.trim_end().len(), 0, message)
+    }
+
+    /// Converts an error from a nom parser that was run over `input`.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<Span<'_>>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => Self::at_end(input, "unexpected end of input"),
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                let rest = err.input.fragment();
+                let token = rest
+                    .split(char::is_whitespace)
+                    .next()
+                    .filter(|token| !token.is_empty())
+                    .map_or(0, str::len);
+
+                Self::new(
+                    input,
+                    err.input.location_offset(),
+                    token,
+                    describe(err.code),
+                )
+            }
+        }
+    }
+
+    /// Replaces the text shown next to the highlighted span.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The 1-based line and column the highlighted span starts at.
+    pub fn location(&self) -> (usize, usize) {
+        let before = &self.input[..self.span.offset()];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map_or(0, |s| s.chars().count()) + 1;
+        (line, column)
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "expected a number".to_owned(),
+        ErrorKind::Eof => "unexpected trailing input".to_owned(),
+        ErrorKind::Char | ErrorKind::OneOf => "unexpected character".to_owned(),
+        kind => format!("unexpected input ({})", kind.description()),
+    }
+}
+
+/// Rejects the first character of `fragment`, a slice of `input`, that is not `allowed`.
+pub fn check_chars(
+    input: &str,
+    fragment: &str,
+    allowed: impl Fn(char) -> bool,
+    message: impl Into<String>,
+) -> Result<(), ParseError> {
+    match fragment.char_indices().find(|&(_, c)| !allowed(c)) {
+        Some((i, c)) => Err(ParseError::at(
+            input,
+            &fragment[i..i + c.len_utf8()],
+            message,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Runs `parser` over the whole of `input`, allowing only trailing whitespace after it.
+pub fn parse_complete<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(Span<'a>) -> IResult<Span<'a>, T>,
+) -> Result<T, ParseError> {
+    all_consuming(terminated(parser, multispace0))(Span::new(input))
+        .map(|(_, value)| value)
+        .map_err(|err| ParseError::from_nom(input, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::{digit1, line_ending};
+    use nom::multi::separated_list1;
+
+    const INPUT: &str = "12 34\nfoo 56";
+
+    #[test]
+    fn points_at_a_fragment() {
+        let foo = &INPUT[6..9];
+        let err = ParseError::at(INPUT, foo, "not a number");
+        assert_eq!((2, 1), err.location());
+        assert_eq!(SourceSpan::from((6, 3)), err.span);
+    }
+
+    #[test]
+    fn foreign_fragments_point_at_the_start() {
+        let err = ParseError::at(INPUT, "foo", "not a number");
+        assert_eq!((1, 1), err.location());
+    }
+
+    #[test]
+    fn rejects_the_first_invalid_character() {
+        assert!(check_chars(INPUT, &INPUT[..5], |c| c.is_ascii_digit() || c == ' ', "").is_ok());
+        let err = check_chars(INPUT, INPUT, |c| c.is_ascii_digit() || c == ' ', "").unwrap_err();
+        assert_eq!((1, 6), err.location());
+    }
+
+    #[test]
+    fn locates_nom_errors() {
+        let numbers = |input| separated_list1(nom::character::complete::space1, digit1)(input);
+        let err = parse_complete(INPUT, |input| separated_list1(line_ending, numbers)(input))
+            .unwrap_err();
+        assert_eq!((2, 1), err.location());
+        assert_eq!(SourceSpan::from((6, 3)), err.span);
+    }
+}
@@ -0,0 +1,117 @@
+use std::ops::RangeInclusive;
+
+/// A small seeded generator (SplitMix64) for puzzle input generators.
+///
+/// It has no dependencies and no platform specific state, so a seed produces the same
+/// numbers everywhere, which keeps generated inputs reproducible.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range<T: Uniform>(&mut self, range: RangeInclusive<T>) -> T {
+        let (low, high) = (range.start().to_i128(), range.end().to_i128());
+        assert!(low <= high, "empty range");
+
+        let span = (high - low + 1) as u128;
+        let offset = match span {
+            // The whole domain of a 64 bit type.
+            0x1_0000_0000_0000_0000 => self.next_u64() as u128,
+            _ => (self.next_u64() as u128 * span) >> 64,
+        };
+        T::from_i128(low + offset as i128)
+    }
+
+    /// An index into a collection of `len` items.
+    pub fn below(&mut self, len: usize) -> usize {
+        self.range(0..=len - 1)
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+/// Integers that [`Rng::range`] can produce.
+pub trait Uniform: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! uniform {
+    ($($t:ty)*) => {$(
+        impl Uniform for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Self {
+                value as $t
+            }
+        }
+    )*};
+}
+
+uniform!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_deterministic() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert_eq!(
+            (0..10).map(|_| a.next_u64()).collect::<Vec<_>>(),
+            (0..10).map(|_| b.next_u64()).collect::<Vec<_>>()
+        );
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3i32..=3)));
+            assert!(rng.below(5) < 5);
+        }
+        assert_eq!(4, rng.range(4u8..=4));
+        rng.range(0..=u64::MAX);
+        rng.range(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn shuffles_a_permutation() {
+        let mut items = (0..50).collect::<Vec<_>>();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!((0..50).collect::<Vec<_>>(), items);
+        items.sort();
+        assert_eq!((0..50).collect::<Vec<_>>(), items);
+    }
+}
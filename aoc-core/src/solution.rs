@@ -0,0 +1,135 @@
+use crate::Part;
+use miette::Diagnostic;
+use std::fmt::Display;
+use std::time::Duration;
+
+/// A day's puzzle, split into a parse phase shared by both parts and one solve phase per part.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Error: Diagnostic + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error>;
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error>;
+}
+
+/// The rendered answer of one part together with how long each phase took.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub value: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Evaluates `body` inside a Tracy frame called `name` when the `tracy` feature is on.
+macro_rules! phase {
+    ($name:literal, $body:expr) => {{
+        #[cfg(feature = "tracy")]
+        let _frame = tracy_client::Client::running()
+            .map(|client| client.non_continuous_frame(tracy_client::frame_name!($name)));
+        $body
+    }};
+}
+
+/// Times a phase. WebAssembly without JavaScript bindings has no clock to read, so there every
+/// phase takes no time rather than panicking.
+struct Stopwatch(
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))] std::time::Instant,
+);
+
+impl Stopwatch {
+    fn start() -> Self {
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        {
+            Self(std::time::Instant::now())
+        }
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        {
+            Self()
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+        {
+            self.0.elapsed()
+        }
+        #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+        {
+            Duration::ZERO
+        }
+    }
+}
+
+#[tracing::instrument(skip(input))]
+pub fn run<S: Solution>(input: &str, part: Part) -> miette::Result<Answer> {
+    let start = Stopwatch::start();
+    let parsed = phase!("parse", S::parse(input))?;
+    let parse = start.elapsed();
+
+    let start = Stopwatch::start();
+    let value = phase!(
+        "solve",
+        match part {
+            Part::One => S::part1(&parsed)?.to_string(),
+            Part::Two => S::part2(&parsed)?.to_string(),
+        }
+    );
+    let solve = start.elapsed();
+
+    Ok(Answer {
+        value,
+        parse,
+        solve,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::ParseIntError;
+
+    #[derive(thiserror::Error, Diagnostic, Debug)]
+    #[error(transparent)]
+    struct NumberError(#[from] ParseIntError);
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+
+        type Input = Vec<u32>;
+        type Error = NumberError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            Ok(input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+            Ok(input.iter().product::<u32>())
+        }
+    }
+
+    #[test]
+    fn runs_each_part_on_the_shared_input() -> miette::Result<()> {
+        assert_eq!("10", run::<Sum>("1 2 3 4", Part::One)?.value);
+        assert_eq!("24", run::<Sum>("1 2 3 4", Part::Two)?.value);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors_are_reported() {
+        assert!(run::<Sum>("1 two", Part::One).is_err());
+    }
+}
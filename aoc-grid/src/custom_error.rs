@@ -0,0 +1,16 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug, PartialEq, Eq)]
+pub enum GridError {
+    #[error("row {row} has {found} cells, expected {expected}")]
+    #[diagnostic(
+        code(aoc::ragged_grid),
+        help("every line of a grid must have the same length")
+    )]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
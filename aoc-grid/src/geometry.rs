@@ -0,0 +1,183 @@
+use std::ops::{Add, Sub};
+
+/// One of the four orthogonal directions, with `Up` pointing towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// The unit step taken when moving in this direction.
+    pub fn delta(self) -> IPoint {
+        match self {
+            Direction::Up => IPoint::new(0, -1),
+            Direction::Right => IPoint::new(1, 0),
+            Direction::Down => IPoint::new(0, 1),
+            Direction::Left => IPoint::new(-1, 0),
+        }
+    }
+}
+
+/// A grid cell, `x` being the column and `y` the row counted from the top.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The neighbouring point in `dir`, or `None` when that would leave the first quadrant.
+    pub fn checked_step(self, dir: Direction) -> Option<Self> {
+        let delta = dir.delta();
+        Some(Self::new(
+            self.x.checked_add_signed(delta.x as isize)?,
+            self.y.checked_add_signed(delta.y as isize)?,
+        ))
+    }
+
+    /// The neighbouring point in `dir`, or `None` when that would leave a `width` by `height` area.
+    pub fn step_within(self, dir: Direction, width: usize, height: usize) -> Option<Self> {
+        self.checked_step(dir)
+            .filter(|next| next.x < width && next.y < height)
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+/// A signed point for walks that are not confined to a grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IPoint {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl IPoint {
+    pub const ORIGIN: IPoint = IPoint::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The point `amount` steps away in `dir`.
+    pub fn step(self, dir: Direction, amount: i64) -> Self {
+        let delta = dir.delta();
+        Self::new(self.x + delta.x * amount, self.y + delta.y * amount)
+    }
+
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for IPoint {
+    type Output = IPoint;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        IPoint::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for IPoint {
+    type Output = IPoint;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        IPoint::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl From<Point> for IPoint {
+    fn from(point: Point) -> Self {
+        IPoint::new(point.x as i64, point.y as i64)
+    }
+}
+
+impl TryFrom<IPoint> for Point {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: IPoint) -> Result<Self, Self::Error> {
+        Ok(Point::new(point.x.try_into()?, point.y.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_four_times_is_a_full_circle() {
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_right().turn_right().turn_right().turn_right());
+            assert_eq!(dir.opposite(), dir.turn_left().turn_left());
+            assert_eq!(dir.turn_left(), dir.turn_right().opposite());
+        }
+    }
+
+    #[test]
+    fn steps_stay_within_bounds() {
+        let corner = Point::new(0, 0);
+        assert_eq!(None, corner.step_within(Direction::Up, 2, 2));
+        assert_eq!(None, corner.step_within(Direction::Left, 2, 2));
+        assert_eq!(
+            Some(Point::new(1, 0)),
+            corner.step_within(Direction::Right, 2, 2)
+        );
+        assert_eq!(None, Point::new(1, 1).step_within(Direction::Down, 2, 2));
+    }
+
+    #[test]
+    fn measures_manhattan_distance() {
+        assert_eq!(9, Point::new(1, 6).manhattan(Point::new(5, 11)));
+        assert_eq!(
+            10,
+            IPoint::ORIGIN.manhattan(
+                IPoint::ORIGIN
+                    .step(Direction::Up, 4)
+                    .step(Direction::Left, 6)
+            )
+        );
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{Direction, GridError, Point};
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+const DIAGONAL: [(isize, isize); 4] = [(1, -1), (1, 1), (-1, 1), (-1, -1)];
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, GridError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            if row == 0 {
+                width = cells_in_row.len();
+            } else if cells_in_row.len() != width {
+                return Err(GridError::Ragged {
+                    row,
+                    expected: width,
+                    found: cells_in_row.len(),
+                });
+            }
+            cells.extend(cells_in_row);
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Whether `point` lies on the outermost ring of cells.
+    pub fn is_edge(&self, point: Point) -> bool {
+        self.contains(point)
+            && (point.x == 0
+                || point.y == 0
+                || point.x == self.width - 1
+                || point.y == self.height - 1)
+    }
+
+    /// The neighbour of `point` in `dir`, if it is inside the grid.
+    pub fn step(&self, point: Point, dir: Direction) -> Option<Point> {
+        point.step_within(dir, self.width, self.height)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} out of bounds");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {x} out of bounds");
+        (0..self.height).map(move |y| &self[Point::new(x, y)])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    /// Every position in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.height).flat_map(|y| (0..self.width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell together with its position, in reading order.
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The in-bounds points above, right of, below and left of `point`.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// The in-bounds points surrounding `point`, diagonals included.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let diagonals = DIAGONAL.iter().filter_map(move |&(dx, dy)| {
+            Some(Point::new(
+                point.x.checked_add_signed(dx)?,
+                point.y.checked_add_signed(dy)?,
+            ))
+        });
+
+        self.neighbours4(point)
+            .chain(diagonals.filter(|&neighbour| self.contains(neighbour)))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        self.map_indexed(|_, cell| f(cell))
+    }
+
+    pub fn map_indexed<U>(&self, mut f: impl FnMut(Point, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self
+                .enumerate()
+                .map(|(point, cell)| f(point, cell))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point).then(|| point.y * self.width + point.x)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self {
+        self.rebuild(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rebuild(self.height, self.width, |p| {
+            Point::new(p.y, self.height - 1 - p.x)
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.rebuild(self.height, self.width, |p| {
+            Point::new(self.width - 1 - p.y, p.x)
+        })
+    }
+
+    /// Builds a `width` by `height` grid whose cell at each point is copied from `source(point)`.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(|point| self[source(point)].clone())
+            .collect();
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: From<char>> Grid<T> {
+    /// Parses one row per line, converting every character into a cell.
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(T::from).collect()),
+        )
+    }
+}
+
+impl<T: From<char>> FromStr for Grid<T> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.offset(point) {
+            Some(offset) => &self.cells[offset],
+            None => panic!(
+                "{point:?} out of bounds for a {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.offset(point) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!("{point:?} out of bounds for a {width}x{height} grid"),
+        }
+    }
+}
+
+/// Renders the grid back to text, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc
+def";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT).unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = grid();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!('f', grid[Point::new(2, 1)]);
+        assert_eq!(Some(&'b'), grid.get(Point::new(1, 0)));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            }),
+            Grid::<char>::parse("abc\nde")
+        );
+    }
+
+    #[test]
+    fn displays_back_to_text() {
+        assert_eq!(INPUT, grid().to_string());
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["ad", "be", "cf"], columns);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = grid();
+        let points = |points: &[(usize, usize)]| {
+            points
+                .iter()
+                .map(|&(x, y)| Point::new(x, y))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            points(&[(1, 0), (0, 1)]),
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            points(&[(1, 0), (2, 1), (0, 1), (2, 0), (0, 0)]),
+            grid.neighbours8(Point::new(1, 1)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = grid();
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_counterclockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counterclockwise());
+    }
+}
@@ -0,0 +1,7 @@
+mod custom_error;
+mod geometry;
+mod grid;
+
+pub use custom_error::GridError;
+pub use geometry::{Direction, IPoint, Point};
+pub use grid::Grid;
@@ -0,0 +1,112 @@
+use crate::Color;
+
+/// A picture to save as an image, like the outline of a lagoon: shapes over a background, in
+/// whatever units suit the puzzle, with `x` growing to the right and `y` downwards from the top
+/// left corner. On grids a unit is usually one tile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drawing {
+    pub title: String,
+    pub width: f64,
+    pub height: f64,
+    pub background: Color,
+    /// Drawn in order, so later shapes cover earlier ones.
+    pub shapes: Vec<Shape>,
+}
+
+/// One thing in a [`Drawing`], in the drawing's units.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        fill: Color,
+    },
+    Circle {
+        x: f64,
+        y: f64,
+        radius: f64,
+        fill: Color,
+    },
+    /// A closed outline through the points.
+    Polygon {
+        points: Vec<(f64, f64)>,
+        fill: Color,
+        stroke: Option<Stroke>,
+    },
+    /// An open line through the points, like a path across a grid.
+    Polyline {
+        points: Vec<(f64, f64)>,
+        stroke: Stroke,
+    },
+}
+
+/// How a line is drawn. Its width is in pixels of the image rather than in the drawing's units,
+/// so that it stays visible however far the drawing is scaled down.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stroke {
+    pub color: Color,
+    pub width: f64,
+}
+
+impl Stroke {
+    pub const fn new(color: Color, width: f64) -> Self {
+        Self { color, width }
+    }
+}
+
+impl Drawing {
+    /// The background behind every drawing, darker than anything drawn on it.
+    pub const BACKGROUND: Color = Color::rgb(15, 15, 25);
+
+    pub fn new(title: impl Into<String>, width: f64, height: f64) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            height,
+            background: Self::BACKGROUND,
+            shapes: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, shape: Shape) {
+        self.shapes.push(shape);
+    }
+
+    /// The pixels per unit that make the longer side of the drawing `size` pixels long.
+    pub(crate) fn scale(&self, size: u32) -> f64 {
+        let longest = self.width.max(self.height);
+        if longest > 0.0 {
+            f64::from(size) / longest
+        } else {
+            1.0
+        }
+    }
+
+    /// The width and height in pixels of the drawing with its longer side `size` pixels long,
+    /// never less than one pixel.
+    pub(crate) fn pixels(&self, size: u32) -> (u32, u32) {
+        let scale = self.scale(size);
+        let pixels = |length: f64| ((length * scale).ceil() as u32).max(1);
+        (pixels(self.width), pixels(self.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_the_longer_side_to_the_size() {
+        let drawing = Drawing::new("wide", 40.0, 10.0);
+        assert_eq!(25.0, drawing.scale(1000));
+        assert_eq!((1000, 250), drawing.pixels(1000));
+
+        let tall = Drawing::new("tall", 3.0, 2_000_000.0);
+        assert_eq!((1, 100), tall.pixels(100));
+
+        let empty = Drawing::new("empty", 0.0, 0.0);
+        assert_eq!((1, 1), empty.pixels(100));
+    }
+}
@@ -0,0 +1,50 @@
+//! Pictures of what the solvers work on, like the pipe loop of day 10 or the path of a crucible,
+//! drawn as scenes that the terminal can show or as drawings to save as SVG or PNG images.
+
+mod drawing;
+mod png;
+mod scene;
+mod svg;
+mod terminal;
+
+pub use drawing::{Drawing, Shape, Stroke};
+pub use png::to_png;
+pub use scene::{Cell, Color, Scene};
+pub use svg::to_svg;
+pub use terminal::to_ansi;
+
+use aoc_core::{Part, Solution};
+
+/// Draws the state a day's solver works on, for the days where it is worth seeing.
+pub trait Visualize: Solution {
+    /// Passes the frames of `part` to `show` in order, the last one showing the state the answer
+    /// is read from. Frames before it show how the state got there, where that is interesting.
+    fn visualize(
+        input: &Self::Input,
+        part: Part,
+        show: &mut dyn FnMut(Scene),
+    ) -> Result<(), Self::Error>;
+}
+
+/// Parses `input` and draws `part` of `V` from it.
+pub fn visualize<V: Visualize>(
+    input: &str,
+    part: Part,
+    show: &mut dyn FnMut(Scene),
+) -> miette::Result<()> {
+    let parsed = V::parse(input)?;
+    V::visualize(&parsed, part, show)?;
+    Ok(())
+}
+
+/// Draws the state a day's answer is read from as a picture to save, for the days where a
+/// picture says more than a grid of characters, or where the state is too big for a terminal.
+pub trait Export: Solution {
+    fn export(input: &Self::Input, part: Part) -> Result<Drawing, Self::Error>;
+}
+
+/// Parses `input` and draws `part` of `E` from it.
+pub fn export<E: Export>(input: &str, part: Part) -> miette::Result<Drawing> {
+    let parsed = E::parse(input)?;
+    Ok(E::export(&parsed, part)?)
+}
@@ -0,0 +1,163 @@
+use crate::{Color, Drawing, Shape, Stroke};
+use std::io;
+use tiny_skia::{FillRule, LineCap, LineJoin, Paint, Path, PathBuilder, Pixmap, Rect, Transform};
+
+/// `drawing` rasterized as a PNG file with its longer side `size` pixels long.
+pub fn to_png(drawing: &Drawing, size: u32) -> io::Result<Vec<u8>> {
+    let (width, height) = drawing.pixels(size);
+    let mut pixmap = Pixmap::new(width, height)
+        .ok_or_else(|| io::Error::other(format!("cannot draw {width}x{height} pixels")))?;
+    pixmap.fill(tiny_skia::Color::from_rgba8(
+        drawing.background.r,
+        drawing.background.g,
+        drawing.background.b,
+        255,
+    ));
+
+    // Points are scaled in `f64` before they become `f32`, which would blur coordinates in the
+    // millions like those of day 18.
+    let scale = drawing.scale(size);
+    let n = |value: f64| (value * scale) as f32;
+    let identity = Transform::identity();
+
+    for shape in &drawing.shapes {
+        match shape {
+            Shape::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+            } => {
+                // Edges snapped to whole pixels make neighbouring tiles meet without a seam,
+                // and never leave the rasterizer a sliver narrower than a pixel to smooth.
+                let (left, top) = (n(*x).round(), n(*y).round());
+                let (right, bottom) = (n(x + width).round(), n(y + height).round());
+                let mut paint = paint(*fill);
+                paint.anti_alias = false;
+                if let Some(rect) = Rect::from_ltrb(left, top, right, bottom) {
+                    pixmap.fill_rect(rect, &paint, identity, None);
+                }
+            }
+            Shape::Circle { x, y, radius, fill } => {
+                if let Some(circle) = PathBuilder::from_circle(n(*x), n(*y), n(*radius)) {
+                    pixmap.fill_path(&circle, &paint(*fill), FillRule::Winding, identity, None);
+                }
+            }
+            Shape::Polygon {
+                points,
+                fill,
+                stroke,
+            } => {
+                let Some(outline) = path(points.iter().map(|&(x, y)| (n(x), n(y))), true) else {
+                    continue;
+                };
+                pixmap.fill_path(&outline, &paint(*fill), FillRule::EvenOdd, identity, None);
+                if let Some(stroke) = stroke {
+                    draw_line(&mut pixmap, &outline, *stroke);
+                }
+            }
+            Shape::Polyline { points, stroke } => {
+                if let Some(line) = path(points.iter().map(|&(x, y)| (n(x), n(y))), false) {
+                    draw_line(&mut pixmap, &line, *stroke);
+                }
+            }
+        }
+    }
+
+    pixmap.encode_png().map_err(io::Error::other)
+}
+
+fn paint(Color { r, g, b }: Color) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(r, g, b, 255);
+    paint.anti_alias = true;
+    paint
+}
+
+/// A path through `points`, or `None` for fewer than two of them.
+fn path(mut points: impl Iterator<Item = (f32, f32)>, close: bool) -> Option<Path> {
+    let mut builder = PathBuilder::new();
+    let (x, y) = points.next()?;
+    builder.move_to(x, y);
+    for (x, y) in points {
+        builder.line_to(x, y);
+    }
+    if close {
+        builder.close();
+    }
+    builder.finish()
+}
+
+fn draw_line(pixmap: &mut Pixmap, path: &Path, stroke: Stroke) {
+    let style = tiny_skia::Stroke {
+        width: stroke.width as f32,
+        line_join: LineJoin::Round,
+        line_cap: LineCap::Round,
+        ..Default::default()
+    };
+    pixmap.stroke_path(
+        path,
+        &paint(stroke.color),
+        &style,
+        Transform::identity(),
+        None,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(png: &[u8], x: u32, y: u32) -> Color {
+        let pixmap = Pixmap::decode_png(png).unwrap();
+        let pixel = pixmap.pixel(x, y).unwrap();
+        Color::rgb(pixel.red(), pixel.green(), pixel.blue())
+    }
+
+    #[test]
+    fn rasterizes_shapes_over_the_background() -> io::Result<()> {
+        let mut drawing = Drawing::new("squares", 4.0, 2.0);
+        drawing.push(Shape::Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+            fill: Color::SOLID,
+        });
+        drawing.push(Shape::Polygon {
+            points: vec![(2.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0)],
+            fill: Color::FILL,
+            stroke: None,
+        });
+        drawing.push(Shape::Circle {
+            x: 3.0,
+            y: 1.0,
+            radius: 0.5,
+            fill: Color::ACCENT,
+        });
+
+        let png = to_png(&drawing, 40)?;
+        let pixmap = Pixmap::decode_png(&png).unwrap();
+        assert_eq!((40, 20), (pixmap.width(), pixmap.height()));
+        assert_eq!(Color::SOLID, pixel(&png, 5, 5));
+        assert_eq!(Drawing::BACKGROUND, pixel(&png, 15, 15));
+        assert_eq!(Color::FILL, pixel(&png, 22, 18));
+        assert_eq!(Color::ACCENT, pixel(&png, 30, 10));
+        Ok(())
+    }
+
+    #[test]
+    fn strokes_lines_in_pixels() -> io::Result<()> {
+        let mut drawing = Drawing::new("path", 2_000_000.0, 1_000_000.0);
+        drawing.push(Shape::Polyline {
+            points: vec![(0.0, 500_000.0), (2_000_000.0, 500_000.0)],
+            stroke: Stroke::new(Color::ACCENT, 4.0),
+        });
+
+        let png = to_png(&drawing, 20)?;
+        assert_eq!(Color::ACCENT, pixel(&png, 10, 4));
+        assert_eq!(Drawing::BACKGROUND, pixel(&png, 10, 1));
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use aoc_grid::Grid;
+
+/// A 24-bit colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    /// Whatever is only there for context, like tiles off the loop.
+    pub const DIM: Color = Color::rgb(90, 90, 100);
+    /// The thing the answer counts, like the loop or the rounded rocks.
+    pub const ACCENT: Color = Color::rgb(255, 200, 60);
+    /// A background for areas, like the tiles the loop encloses.
+    pub const FILL: Color = Color::rgb(35, 70, 120);
+    /// Solid obstacles, like walls and cube-shaped rocks.
+    pub const SOLID: Color = Color::rgb(150, 150, 160);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// A colour from cold dark blue at 0 through red to bright yellow at 1, for values such as
+    /// heat loss. Values outside `0.0..=1.0` are clamped.
+    pub fn heat(t: f64) -> Self {
+        const STOPS: [Color; 4] = [
+            Color::rgb(20, 30, 80),
+            Color::rgb(120, 40, 120),
+            Color::rgb(220, 60, 40),
+            Color::rgb(255, 230, 90),
+        ];
+
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let scaled = t * (STOPS.len() - 1) as f64;
+        let i = (scaled.floor() as usize).min(STOPS.len() - 2);
+        let (from, to, f) = (STOPS[i], STOPS[i + 1], scaled - i as f64);
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * f).round() as u8;
+        Color::rgb(mix(from.r, to.r), mix(from.g, to.g), mix(from.b, to.b))
+    }
+}
+
+/// One character of a scene with its colours, `None` leaving the terminal's own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+}
+
+impl Cell {
+    pub const fn new(glyph: char) -> Self {
+        Self {
+            glyph,
+            fg: None,
+            bg: None,
+        }
+    }
+
+    pub const fn fg(self, color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn bg(self, color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..self
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::new(' ')
+    }
+}
+
+/// One frame of a visualization: a grid of cells with a line saying what it shows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scene {
+    pub title: String,
+    pub cells: Grid<Cell>,
+}
+
+impl Scene {
+    pub fn new(title: impl Into<String>, cells: Grid<Cell>) -> Self {
+        Self {
+            title: title.into(),
+            cells,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heat_runs_from_cold_to_hot() {
+        assert_eq!(Color::rgb(20, 30, 80), Color::heat(0.0));
+        assert_eq!(Color::rgb(255, 230, 90), Color::heat(1.0));
+        assert_eq!(Color::heat(1.0), Color::heat(7.0));
+        assert_eq!(Color::heat(0.0), Color::heat(f64::NAN));
+        assert_eq!(Color::rgb(120, 40, 120), Color::heat(1.0 / 3.0));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Color, Drawing, Shape, Stroke};
+use std::fmt::Write;
+
+/// `drawing` as an SVG document with its longer side `size` pixels long. Coordinates are scaled
+/// to pixels here rather than by a `viewBox`, so that strokes keep their width in pixels.
+pub fn to_svg(drawing: &Drawing, size: u32) -> String {
+    let scale = drawing.scale(size);
+    let (width, height) = drawing.pixels(size);
+
+    let mut out = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">
+<title>{}</title>
+<rect width="{width}" height="{height}" fill="{}"/>
+"#,
+        escape(&drawing.title),
+        hex(drawing.background)
+    );
+
+    let n = |value: f64| number(value * scale);
+    let points = |points: &[(f64, f64)]| {
+        points
+            .iter()
+            .map(|&(x, y)| format!("{},{}", n(x), n(y)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    for shape in &drawing.shapes {
+        let _ = match shape {
+            Shape::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+            } => writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                n(*x),
+                n(*y),
+                n(*width),
+                n(*height),
+                hex(*fill)
+            ),
+            Shape::Circle { x, y, radius, fill } => writeln!(
+                out,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+                n(*x),
+                n(*y),
+                n(*radius),
+                hex(*fill)
+            ),
+            Shape::Polygon {
+                points: corners,
+                fill,
+                stroke,
+            } => writeln!(
+                out,
+                r#"<polygon points="{}" fill="{}"{}/>"#,
+                points(corners),
+                hex(*fill),
+                stroke.map(attributes).unwrap_or_default()
+            ),
+            Shape::Polyline {
+                points: path,
+                stroke,
+            } => writeln!(
+                out,
+                r#"<polyline points="{}" fill="none"{}/>"#,
+                points(path),
+                attributes(*stroke)
+            ),
+        };
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+fn attributes(stroke: Stroke) -> String {
+    format!(
+        r#" stroke="{}" stroke-width="{}" stroke-linejoin="round" stroke-linecap="round""#,
+        hex(stroke.color),
+        number(stroke.width)
+    )
+}
+
+fn hex(Color { r, g, b }: Color) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// `value` to two decimals, which is finer than a pixel, without trailing zeros.
+fn number(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    // Rounding can leave `-0`, which is valid but looks odd.
+    format!("{}", rounded + 0.0)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_shapes_in_pixels() {
+        let mut drawing = Drawing::new("lagoon <62>", 4.0, 2.0);
+        drawing.push(Shape::Rect {
+            x: 0.0,
+            y: 0.0,
+            width: 1.0,
+            height: 1.0,
+            fill: Color::rgb(255, 0, 16),
+        });
+        drawing.push(Shape::Circle {
+            x: 1.5,
+            y: 0.5,
+            radius: 1.0 / 3.0,
+            fill: Color::ACCENT,
+        });
+        drawing.push(Shape::Polygon {
+            points: vec![(0.0, 0.0), (4.0, 0.0), (4.0, 2.0)],
+            fill: Color::FILL,
+            stroke: Some(Stroke::new(Color::ACCENT, 2.0)),
+        });
+        drawing.push(Shape::Polyline {
+            points: vec![(0.5, 0.5), (3.5, 0.5)],
+            stroke: Stroke::new(Color::SOLID, 1.5),
+        });
+
+        assert_eq!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50" viewBox="0 0 100 50">
+<title>lagoon &lt;62&gt;</title>
+<rect width="100" height="50" fill="#0f0f19"/>
+<rect x="0" y="0" width="25" height="25" fill="#ff0010"/>
+<circle cx="37.5" cy="12.5" r="8.33" fill="#ffc83c"/>
+<polygon points="0,0 100,0 100,50" fill="#234678" stroke="#ffc83c" stroke-width="2" stroke-linejoin="round" stroke-linecap="round"/>
+<polyline points="12.5,12.5 87.5,12.5" fill="none" stroke="#9696a0" stroke-width="1.5" stroke-linejoin="round" stroke-linecap="round"/>
+</svg>
+"##,
+            to_svg(&drawing, 100)
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Cell, Color, Scene};
+use std::fmt::Write;
+
+/// `scene` as text for a terminal: its title, then one line per row of cells. With `color`, the
+/// cells get their colours through 24-bit ANSI escape codes; without, only the glyphs are left.
+pub fn to_ansi(scene: &Scene, color: bool) -> String {
+    let mut out = format!("{}\n", scene.title);
+
+    for row in scene.cells.rows() {
+        let mut current = (None, None);
+        for cell in row {
+            let style = (cell.fg, cell.bg);
+            if color && style != current {
+                out.push_str(&escape(cell));
+                current = style;
+            }
+            out.push(cell.glyph);
+        }
+        if color && current != (None, None) {
+            out.push_str(RESET);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// The escape sequence that switches to the colours of `cell`, starting from the defaults so
+/// that no colour of the previous cell is left over.
+fn escape(cell: &Cell) -> String {
+    let mut code = RESET.to_string();
+    let mut set = |layer: u8, color: Option<Color>| {
+        if let Some(Color { r, g, b }) = color {
+            let _ = write!(code, "\x1b[{layer};2;{r};{g};{b}m");
+        }
+    };
+    set(38, cell.fg);
+    set(48, cell.bg);
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_grid::Grid;
+
+    fn scene() -> Scene {
+        let red = Cell::new('┌').fg(Color::rgb(255, 0, 0));
+        let cells = Grid::from_rows([
+            vec![red, red.bg(Color::rgb(0, 0, 255))],
+            vec![Cell::new('.'), Cell::new('.')],
+        ])
+        .unwrap();
+        Scene::new("loop", cells)
+    }
+
+    #[test]
+    fn draws_plain_glyphs_without_color() {
+        assert_eq!("loop\n┌┌\n..\n", to_ansi(&scene(), false));
+    }
+
+    #[test]
+    fn switches_colors_only_where_they_change() {
+        assert_eq!(
+            "loop\n\
+             \x1b[0m\x1b[38;2;255;0;0m┌\
+             \x1b[0m\x1b[38;2;255;0;0m\x1b[48;2;0;0;255m┌\x1b[0m\n\
+             ..\n",
+            to_ansi(&scene(), true)
+        );
+    }
+}
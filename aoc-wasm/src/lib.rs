@@ -0,0 +1,59 @@
+//! The solvers compiled to WebAssembly, callable from JavaScript.
+//!
+//! Build with `wasm-pack build aoc-wasm --target web --profile wasm-release` and call
+//! `solve(day, part, input)` from the generated bindings.
+
+use aoc_core::{Answer, Part, Solution};
+use wasm_bindgen::prelude::*;
+
+type Run = fn(&str, Part) -> miette::Result<Answer>;
+
+macro_rules! days {
+    ($($solution:path),* $(,)?) => {
+        const DAYS: &[(u8, Run)] = &[$(
+            (<$solution as Solution>::DAY, aoc_core::run::<$solution>),
+        )*];
+    };
+}
+
+days! {
+    day_01::Day01,
+    day_02::Day02,
+    day_03::Day03,
+    day_04::Day04,
+    day_05::Day05,
+    day_06::Day06,
+    day_07::Day07,
+    day_08::Day08,
+    day_09::Day09,
+    day_10::Day10,
+    day_11::Day11,
+    day_12::Day12,
+    day_13::Day13,
+    day_14::Day14,
+    day_15::Day15,
+    day_16::Day16,
+    day_17::Day17,
+    day_18::Day18,
+}
+
+/// The days with a solver, for building a picker.
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    DAYS.iter().map(|&(day, _)| day).collect()
+}
+
+/// Solves one part of a day. Errors, such as malformed input, come back as the rendered
+/// diagnostic, which JavaScript receives as a thrown string.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    let part = part.to_string().parse::<Part>()?;
+    let &(_, run) = DAYS
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .ok_or_else(|| format!("no solver for day {day}"))?;
+
+    run(input, part)
+        .map(|answer| answer.value)
+        .map_err(|report| format!("{report:?}"))
+}
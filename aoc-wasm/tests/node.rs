@@ -0,0 +1,49 @@
+//! Run under node with `wasm-pack test --node aoc-wasm`; natively these are plain tests.
+
+use aoc_wasm::{days, solve};
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test;
+
+const DAY_07: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+const DAY_12: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn lists_every_day() {
+    // Days added with `just create` extend the list, so only the ones solved so far are fixed.
+    let days = days();
+    assert!(days.len() >= 18);
+    assert_eq!((1..=days.len() as u8).collect::<Vec<u8>>(), days);
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn solves_an_example() {
+    assert_eq!(Ok("6440".to_string()), solve(7, 1, DAY_07));
+    assert_eq!(Ok("5905".to_string()), solve(7, 2, DAY_07));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn solves_a_formerly_parallel_day() {
+    assert_eq!(Ok("21".to_string()), solve(12, 1, DAY_12));
+    assert_eq!(Ok("525152".to_string()), solve(12, 2, DAY_12));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn reports_errors_as_strings() {
+    assert!(solve(7, 1, "32T3X 765").unwrap_err().contains("32T3X"));
+    assert_eq!(Err("no solver for day 25".to_string()), solve(25, 1, DAY_07));
+    assert_eq!(Err("invalid part `3`".to_string()), solve(7, 3, DAY_07));
+}
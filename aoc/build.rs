@@ -0,0 +1,56 @@
+//! Generates one test per entry of the answers file, see `tests/answers.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let answers = Path::new("../answers.txt");
+    println!("cargo:rerun-if-changed={}", answers.display());
+
+    let text = fs::read_to_string(answers).expect("answers.txt is readable");
+    let mut tests = String::new();
+
+    // Malformed lines are left to `aoc::answers::parse`, whose own test reports them.
+    for fields in text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+    {
+        let [day, part, input, answer, rest @ ..] = fields.as_slice() else {
+            continue;
+        };
+        let Ok(day) = day.parse::<u8>() else {
+            continue;
+        };
+        let variant = match *part {
+            "1" => "One",
+            "2" => "Two",
+            _ => continue,
+        };
+        let slow = rest.contains(&"slow");
+
+        writeln!(
+            tests,
+            r#"
+#[test]{ignore}
+fn day_{day:02}_part_{part}() -> miette::Result<()> {{
+    check(&Expected {{
+        day: {day},
+        part: Part::{variant},
+        input: PathBuf::from({input:?}),
+        answer: {answer:?}.to_string(),
+        slow: {slow},
+    }})?;
+    Ok(())
+}}"#,
+            ignore = if slow { "\n#[ignore = \"slow\"]" } else { "" },
+        )
+        .unwrap();
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("answers.rs");
+    fs::write(out, tests).unwrap();
+}
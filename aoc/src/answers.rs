@@ -0,0 +1,161 @@
+use crate::custom_error::AocError;
+use aoc_core::{ParseError, Part};
+use miette::Context;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// The repository root, which the day crates and the inputs in the answers file are relative
+/// to: `AOC_ROOT` if set, else the nearest directory from the current one upwards that holds
+/// an `answers.txt`. Only when there is none does a built binary fall back to the checkout it
+/// was compiled in, which may since have moved.
+pub fn root() -> PathBuf {
+    let cwd = env::current_dir().unwrap_or_default();
+    locate_root(env::var_os("AOC_ROOT").map(PathBuf::from), &cwd)
+}
+
+fn locate_root(explicit: Option<PathBuf>, cwd: &Path) -> PathBuf {
+    explicit
+        .or_else(|| {
+            cwd.ancestors()
+                .find(|dir| dir.join("answers.txt").is_file())
+                .map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+}
+
+/// The checked-in `answers.txt`.
+pub fn default_path() -> PathBuf {
+    root().join("answers.txt")
+}
+
+/// One line of the answers file: the answer a day's part must produce for an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: String,
+    /// Too slow to check on every run, e.g. a brute force that takes minutes.
+    pub slow: bool,
+}
+
+impl Expected {
+    fn parse(text: &str, line: &str) -> Result<Self, ParseError> {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [day, part, input, answer, rest @ ..] = fields.as_slice() else {
+            return Err(ParseError::at(
+                text,
+                line,
+                "expected `day part input answer`",
+            ));
+        };
+
+        let slow = match rest {
+            [] => false,
+            ["slow"] => true,
+            [flag, ..] => return Err(ParseError::at(text, flag, "unknown flag")),
+        };
+
+        Ok(Self {
+            day: day
+                .parse()
+                .map_err(|_| ParseError::at(text, day, "invalid day"))?,
+            part: part
+                .parse()
+                .map_err(|_| ParseError::at(text, part, "invalid part"))?,
+            input: PathBuf::from(input),
+            answer: answer.to_string(),
+            slow,
+        })
+    }
+
+    /// The input file, resolved against the repository root.
+    pub fn input_path(&self) -> PathBuf {
+        root().join(&self.input)
+    }
+}
+
+/// Parses an answers file, skipping blank lines and `#` comments.
+pub fn parse(text: &str) -> Result<Vec<Expected>, ParseError> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| Expected::parse(text, line))
+        .collect()
+}
+
+pub fn load(path: &Path) -> miette::Result<Vec<Expected>> {
+    let text = fs::read_to_string(path)
+        .map_err(AocError::from)
+        .wrap_err_with(|| format!("reading answers {}", path.display()))?;
+    Ok(parse(&text).map_err(AocError::from)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::TempDir;
+
+    #[test]
+    fn parses_entries() -> miette::Result<()> {
+        let entries = parse(
+            "# day part input answer
+07 2 day-07/input2.txt 254494947
+
+05 2 day-05/input2.txt 69841803 slow",
+        )?;
+        assert_eq!(
+            vec![
+                Expected {
+                    day: 7,
+                    part: Part::Two,
+                    input: PathBuf::from("day-07/input2.txt"),
+                    answer: "254494947".to_string(),
+                    slow: false,
+                },
+                Expected {
+                    day: 5,
+                    part: Part::Two,
+                    input: PathBuf::from("day-05/input2.txt"),
+                    answer: "69841803".to_string(),
+                    slow: true,
+                },
+            ],
+            entries
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        let err = parse("01 1 day-01/input1.txt 1\n01 3 day-01/input1.txt 2").unwrap_err();
+        assert_eq!("invalid part", err.message());
+        assert_eq!((2, 4), err.location());
+        assert!(parse("01 1 day-01/input1.txt").is_err());
+        assert!(parse("01 1 day-01/input1.txt 1 fast").is_err());
+    }
+
+    #[test]
+    fn finds_the_root_above_the_current_directory() {
+        let dir = TempDir::new("root");
+        let nested = dir.0.join("day-01").join("src");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.0.join("answers.txt"), "").unwrap();
+
+        assert_eq!(dir.0, locate_root(None, &nested));
+        assert_eq!(dir.0, locate_root(None, &dir.0));
+        let elsewhere = PathBuf::from("/elsewhere");
+        assert_eq!(elsewhere, locate_root(Some(elsewhere.clone()), &nested));
+        // Outside any checkout, the one the binary was built in is all there is.
+        assert_eq!(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+            locate_root(None, Path::new("/"))
+        );
+    }
+
+    #[test]
+    fn checked_in_answers_parse() -> miette::Result<()> {
+        assert!(!load(&default_path())?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::custom_error::AocError;
+use aoc_core::Part;
+use std::time::Duration;
+
+pub const YEAR: u16 = 2023;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!("aoc-2023/", env!("CARGO_PKG_VERSION"));
+
+/// Talks to the Advent of Code website, or anything serving the same paths at `base_url`.
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// A client authenticated with the `session` cookie of a logged-in browser.
+    pub fn new(base_url: &str, session: Option<String>) -> Result<Self, AocError> {
+        let session = session
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(AocError::MissingSession)?;
+
+        Ok(Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        })
+    }
+
+    /// The personal puzzle input of `day`.
+    #[tracing::instrument(skip(self))]
+    pub fn input(&self, day: u8) -> Result<String, AocError> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| request_error(&url, err))?
+            .into_string()
+            .map_err(|err| AocError::Request {
+                url,
+                reason: err.to_string(),
+            })
+    }
+
+    /// Posts `answer` for `part` of `day` and returns the page that comes back.
+    #[tracing::instrument(skip(self))]
+    pub fn answer(&self, day: u8, part: Part, answer: &str) -> Result<String, AocError> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| request_error(&url, err))?
+            .into_string()
+            .map_err(|err| AocError::Request {
+                url,
+                reason: err.to_string(),
+            })
+    }
+}
+
+fn request_error(url: &str, err: ureq::Error) -> AocError {
+    let reason = match err {
+        ureq::Error::Status(400 | 401 | 403, _) => {
+            "the session was rejected, it may have expired".to_string()
+        }
+        ureq::Error::Status(404, _) => "the puzzle is not unlocked yet".to_string(),
+        ureq::Error::Status(status, response) => {
+            format!("HTTP {status} {}", response.status_text())
+        }
+        ureq::Error::Transport(transport) => transport.to_string(),
+    };
+    AocError::Request {
+        url: url.to_string(),
+        reason,
+    }
+}
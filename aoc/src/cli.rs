@@ -0,0 +1,166 @@
+use crate::input::InputSource;
+use crate::report::Format;
+use crate::visualize::Frames;
+use crate::{answers, api, fetch, record};
+use aoc_core::Part;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+    /// Write every span to a trace-event file for `chrome://tracing` or ui.perfetto.dev
+    #[arg(long, global = true, value_name = "PATH")]
+    pub trace: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run one or every solver against its puzzle input
+    Run {
+        /// Day number (`5`, `05`, `day-05`) or `all`
+        day: Selection,
+        /// Part number (`1`, `part1`); both parts when omitted
+        part: Option<Part>,
+        /// Read the puzzle input from this file, or stdin for `-`, instead of the day's input file
+        #[arg(long, value_name = "PATH")]
+        input: Option<InputSource>,
+        #[arg(long, value_enum, default_value_t = record::Format::Text)]
+        format: record::Format,
+        /// Draw the state the answer is read from, or with `all` every frame on the way there
+        #[arg(
+            long,
+            value_enum,
+            value_name = "FRAMES",
+            num_args = 0..=1,
+            default_missing_value = "final",
+            conflicts_with = "format"
+        )]
+        visualize: Option<Frames>,
+    },
+    /// Save a picture of the state a part's answer is read from as an SVG or PNG image
+    Export {
+        /// Day number (`5`, `05`, `day-05`)
+        day: Selection,
+        /// Part number (`1`, `part1`)
+        part: Part,
+        /// Where to save the image; its extension, `.svg` or `.png`, picks the format
+        #[arg(long, short, value_name = "PATH")]
+        output: PathBuf,
+        /// Read the puzzle input from this file, or stdin for `-`, instead of the day's input file
+        #[arg(long, value_name = "PATH")]
+        input: Option<InputSource>,
+        /// Length of the image's longer side, in pixels
+        #[arg(long, default_value_t = 1024, value_parser = clap::value_parser!(u32).range(1..))]
+        size: u32,
+    },
+    /// Check every solver against the known-good answers
+    Verify {
+        /// Day number or `all`
+        #[arg(default_value = "all")]
+        day: Selection,
+        /// Answers file to check against
+        #[arg(long, value_name = "PATH", default_value_os_t = answers::default_path())]
+        answers: PathBuf,
+        /// Also check entries marked `slow`
+        #[arg(long)]
+        slow: bool,
+    },
+    /// Time every solver over several runs and print a table of the results
+    Report {
+        /// Day number or `all`
+        #[arg(default_value = "all")]
+        day: Selection,
+        /// How often to run each part
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        #[arg(long, value_enum, default_value_t = Format::Markdown)]
+        format: Format,
+        /// Replace the report section of README.md instead of printing the table
+        #[arg(long, conflicts_with = "format")]
+        readme: bool,
+        /// Also run parts marked `slow` in the answers file
+        #[arg(long)]
+        slow: bool,
+    },
+    /// Download a day's puzzle input into its crate, caching it for later runs
+    Fetch {
+        /// Day number (`5`, `05`, `day-05`)
+        day: Selection,
+        /// Download again even if the input is cached
+        #[arg(long)]
+        refresh: bool,
+        #[command(flatten)]
+        site: Site,
+    },
+    /// Solve a part and submit the answer, unless earlier submissions already rule it out
+    Submit {
+        /// Day number (`5`, `05`, `day-05`)
+        day: Selection,
+        /// Part number (`1`, `part1`)
+        part: Part,
+        #[command(flatten)]
+        site: Site,
+    },
+    /// Add a day crate made by `just create` to the runner, the wasm build and the fuzz targets
+    Register {
+        /// Day number (`5`, `05`, `day-05`)
+        day: Selection,
+    },
+    /// Re-run a day's tests and solver whenever its sources or inputs change
+    Watch {
+        /// Day number (`5`, `05`, `day-05`)
+        day: Selection,
+        /// Part number (`1`, `part1`); both parts when omitted
+        part: Option<Part>,
+    },
+    /// Print a random puzzle input for a day, e.g. to stress-test or benchmark its solver
+    Gen {
+        /// Day number (`5`, `05`, `day-05`)
+        day: Selection,
+        /// How big an input to make; what it counts, like lines or grid width, depends on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// The same seed always makes the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+/// How to reach adventofcode.com.
+#[derive(Args, Debug)]
+pub struct Site {
+    /// Value of the `session` cookie of a logged-in browser
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+    /// Where to download from, e.g. a local mock server
+    #[arg(long, env = "AOC_BASE_URL", default_value = api::DEFAULT_BASE_URL)]
+    pub base_url: String,
+    /// Where downloaded inputs and past submissions are kept
+    #[arg(long, env = "AOC_CACHE_DIR", default_value_os_t = fetch::default_cache_dir())]
+    pub cache_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        s.trim_start_matches("day-")
+            .parse()
+            .map(Selection::Day)
+            .map_err(|_| format!("invalid day `{s}`"))
+    }
+}
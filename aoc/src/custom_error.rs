@@ -0,0 +1,108 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+    #[error("No solver registered for day {0}")]
+    #[diagnostic(code(aoc::unknown_day))]
+    UnknownDay(u8),
+    #[error("--input can only be used with a single day")]
+    #[diagnostic(code(aoc::invalid_input))]
+    InputForAllDays,
+    #[error("inputs can only be fetched one day at a time")]
+    #[diagnostic(code(aoc::invalid_input))]
+    FetchAllDays,
+    #[error("answers can only be submitted one day at a time")]
+    #[diagnostic(code(aoc::invalid_input))]
+    SubmitAllDays,
+    #[error("inputs can only be generated one day at a time")]
+    #[diagnostic(code(aoc::invalid_input))]
+    GenerateAllDays,
+    #[error("days can only be registered one at a time")]
+    #[diagnostic(code(aoc::invalid_input))]
+    RegisterAllDays,
+    #[error("day {day} has nothing to visualize")]
+    #[diagnostic(
+        code(aoc::invalid_input),
+        help("only days {visualized} can be visualized")
+    )]
+    NoVisualization { day: u8, visualized: String },
+    #[error("images can only be exported one day at a time")]
+    #[diagnostic(code(aoc::invalid_input))]
+    ExportAllDays,
+    #[error("day {day} has nothing to export")]
+    #[diagnostic(code(aoc::invalid_input), help("only days {exported} can be exported"))]
+    NoExport { day: u8, exported: String },
+    #[error("cannot tell what kind of image {} should be", .0.display())]
+    #[diagnostic(
+        code(aoc::invalid_input),
+        help("end the file name in `.svg` or `.png`")
+    )]
+    UnknownImageFormat(std::path::PathBuf),
+    #[error("days can only be watched one at a time")]
+    #[diagnostic(code(aoc::invalid_input))]
+    WatchAllDays,
+    #[error("{0} solver(s) failed")]
+    #[diagnostic(code(aoc::solver_failed))]
+    SolverFailed(usize),
+    #[error("solver panicked")]
+    #[diagnostic(code(aoc::solver_panicked))]
+    SolverPanicked,
+    #[error("expected {expected}, got {found}")]
+    #[diagnostic(code(aoc::wrong_answer))]
+    WrongAnswer { expected: String, found: String },
+    #[error("{0} answer(s) did not match")]
+    #[diagnostic(code(aoc::verify_failed))]
+    VerifyFailed(usize),
+    #[error("README.md has no `<!-- report:start -->` ... `<!-- report:end -->` section")]
+    #[diagnostic(code(aoc::missing_report_markers))]
+    MissingReportMarkers,
+    #[error("no session token to download with")]
+    #[diagnostic(
+        code(aoc::missing_session),
+        help("set AOC_SESSION to the `session` cookie of a logged-in adventofcode.com tab")
+    )]
+    MissingSession,
+    #[error("request to {url} failed: {reason}")]
+    #[diagnostic(code(aoc::request_failed))]
+    Request { url: String, reason: String },
+    #[error("day {day} has no crate at {}", dir.display())]
+    #[diagnostic(
+        code(aoc::missing_day_crate),
+        help("scaffold it first with `just create day-{day:02}`")
+    )]
+    MissingDayCrate { day: u8, dir: std::path::PathBuf },
+    #[error("could not make sense of the response: {0}")]
+    #[diagnostic(code(aoc::unexpected_page))]
+    UnexpectedPage(String),
+    #[error("the part is already solved, with {answer}")]
+    #[diagnostic(code(aoc::already_solved))]
+    AlreadySolved { answer: String },
+    #[error("{answer} was already submitted and was {verdict}")]
+    #[diagnostic(code(aoc::already_submitted))]
+    AlreadySubmitted { answer: String, verdict: String },
+    #[error("{answer} cannot be right, {reason}")]
+    #[diagnostic(code(aoc::out_of_bounds))]
+    OutOfBounds { answer: String, reason: String },
+    #[error("the answer was not accepted: {0}")]
+    #[diagnostic(code(aoc::not_accepted))]
+    NotAccepted(String),
+    #[error("the submission history is corrupt: {0}")]
+    #[diagnostic(
+        code(aoc::corrupt_history),
+        help("fix or delete the file; it only guards against repeat submissions")
+    )]
+    CorruptHistory(String),
+    #[error("{} has no list of days to add the new day to", .0.display())]
+    #[diagnostic(code(aoc::unregistrable))]
+    Unregistrable(std::path::PathBuf),
+    #[error("could not watch for changes: {0}")]
+    #[diagnostic(code(aoc::watch_failed))]
+    Watch(String),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+}
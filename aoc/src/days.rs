@@ -0,0 +1,95 @@
+use crate::answers;
+use aoc_core::{Answer, Part, Solution};
+use aoc_visual::{Drawing, Scene};
+use std::path::PathBuf;
+
+pub type Run = fn(&str, Part) -> miette::Result<Answer>;
+
+/// Makes a random input of some size from a seed.
+pub type Generate = fn(usize, u64) -> String;
+
+/// Draws the frames of a part for an input.
+pub type Visualize = fn(&str, Part, &mut dyn FnMut(Scene)) -> miette::Result<()>;
+
+/// Draws the state a part's answer is read from as a picture to save.
+pub type Export = fn(&str, Part) -> miette::Result<Drawing>;
+
+pub struct Day {
+    pub number: u8,
+    pub run: Run,
+    pub generate: Generate,
+    /// Only the days whose state is worth seeing can draw it.
+    pub visualize: Option<Visualize>,
+    /// Only some days draw pictures to save as images.
+    pub export: Option<Export>,
+    dir: &'static str,
+}
+
+impl Day {
+    /// The day's crate.
+    pub fn dir(&self) -> PathBuf {
+        answers::root().join(self.dir)
+    }
+
+    /// The puzzle input checked into the day crate, `input1.txt` or `input2.txt`.
+    pub fn input_path(&self, part: Part) -> PathBuf {
+        self.dir().join(format!("input{}.txt", part.number()))
+    }
+}
+
+macro_rules! days {
+    ($($solution:path, $dir:literal $(, $marker:ident)*;)*) => {
+        pub const DAYS: &[Day] = &[$(
+            Day {
+                number: <$solution as Solution>::DAY,
+                run: aoc_core::run::<$solution>,
+                generate: aoc_core::generate::<$solution>,
+                visualize: hook!($solution, visualize; $($marker)*),
+                export: hook!($solution, export; $($marker)*),
+                dir: $dir,
+            },
+        )*];
+    };
+}
+
+/// `Some` for the days marked `visualize` or `export`, which implement `aoc_visual::Visualize`
+/// or `aoc_visual::Export`.
+macro_rules! hook {
+    ($solution:path, visualize; visualize $($rest:ident)*) => {
+        Some(aoc_visual::visualize::<$solution>)
+    };
+    ($solution:path, export; export $($rest:ident)*) => {
+        Some(aoc_visual::export::<$solution>)
+    };
+    ($solution:path, $hook:ident; $other:ident $($rest:ident)*) => {
+        hook!($solution, $hook; $($rest)*)
+    };
+    ($solution:path, $hook:ident;) => {
+        None
+    };
+}
+
+days! {
+    day_01::Day01, "day-01";
+    day_02::Day02, "day-02";
+    day_03::Day03, "day-03";
+    day_04::Day04, "day-04";
+    day_05::Day05, "day-05";
+    day_06::Day06, "day-06";
+    day_07::Day07, "day-07";
+    day_08::Day08, "day-08";
+    day_09::Day09, "day-09";
+    day_10::Day10, "day-10", visualize;
+    day_11::Day11, "day-11", export;
+    day_12::Day12, "day-12";
+    day_13::Day13, "day-13";
+    day_14::Day14, "day-14", visualize, export;
+    day_15::Day15, "day-15";
+    day_16::Day16, "day-16", visualize;
+    day_17::Day17, "day-17", visualize, export;
+    day_18::Day18, "day-18", export;
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,165 @@
+use crate::cli::Selection;
+use crate::custom_error::AocError;
+use crate::days::{self, Day, DAYS};
+use crate::input::{self, InputSource};
+use aoc_core::Part;
+use aoc_visual::{to_png, to_svg};
+use std::fs;
+use std::path::Path;
+
+/// The image formats `export` writes, told apart by the extension of the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn of(path: &Path) -> Result<Self, AocError> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("svg") => Ok(ImageFormat::Svg),
+            Some("png") => Ok(ImageFormat::Png),
+            _ => Err(AocError::UnknownImageFormat(path.to_path_buf())),
+        }
+    }
+}
+
+/// Draws `part` of a single day for its puzzle input, or `source` if given, and saves the
+/// picture at `output` with its longer side `size` pixels long.
+#[tracing::instrument]
+pub fn export(
+    selection: Selection,
+    part: Part,
+    source: Option<InputSource>,
+    output: &Path,
+    size: u32,
+) -> miette::Result<()> {
+    let Selection::Day(number) = selection else {
+        return Err(AocError::ExportAllDays.into());
+    };
+    let day = days::find(number).ok_or(AocError::UnknownDay(number))?;
+    let Some(export) = day.export else {
+        return Err(AocError::NoExport {
+            day: number,
+            exported: exported(DAYS),
+        }
+        .into());
+    };
+    let format = ImageFormat::of(output)?;
+
+    let explicit = source.map(|source| source.read()).transpose()?;
+    let input = input::load(explicit.as_deref(), day, part)?;
+    let drawing = export(&input, part)?;
+
+    let image = match format {
+        ImageFormat::Svg => to_svg(&drawing, size).into_bytes(),
+        ImageFormat::Png => to_png(&drawing, size).map_err(AocError::from)?,
+    };
+    fs::write(output, image).map_err(AocError::from)?;
+    println!(
+        "day {number:02} part {}: {} in {}",
+        part.number(),
+        drawing.title,
+        output.display()
+    );
+    Ok(())
+}
+
+/// The numbers of the days that can be exported, like `11, 18`.
+pub fn exported(days: &[Day]) -> String {
+    days.iter()
+        .filter(|day| day.export.is_some())
+        .map(|day| day.number.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::TempDir;
+
+    #[test]
+    fn lists_the_days_that_export() {
+        assert_eq!("11, 14, 17, 18", exported(DAYS));
+    }
+
+    #[test]
+    fn tells_formats_by_extension() {
+        assert_eq!(
+            ImageFormat::Svg,
+            ImageFormat::of(Path::new("lagoon.svg")).unwrap()
+        );
+        assert_eq!(
+            ImageFormat::Png,
+            ImageFormat::of(Path::new("out/lagoon.PNG")).unwrap()
+        );
+        assert!(matches!(
+            ImageFormat::of(Path::new("lagoon.jpg")),
+            Err(AocError::UnknownImageFormat(_))
+        ));
+        assert!(ImageFormat::of(Path::new("lagoon")).is_err());
+    }
+
+    #[test]
+    fn every_exported_day_draws_its_generated_input() -> miette::Result<()> {
+        for day in DAYS {
+            let Some(export) = day.export else {
+                continue;
+            };
+            let input = (day.generate)(20, 1);
+            for part in Part::BOTH {
+                let drawing = export(&input, part)?;
+                assert!(
+                    !drawing.shapes.is_empty(),
+                    "day {} part {part} drew nothing",
+                    day.number
+                );
+                assert!(to_svg(&drawing, 64).ends_with("</svg>\n"));
+                to_png(&drawing, 64).map_err(AocError::from)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn writes_the_image_for_a_given_input() -> miette::Result<()> {
+        let dir = TempDir::new("export");
+        let input = dir.0.join("input.txt");
+        fs::write(
+            &input,
+            "R 2 (#000020)\nD 2 (#000021)\nL 2 (#000022)\nU 2 (#000023)",
+        )
+        .map_err(AocError::from)?;
+
+        let svg = dir.0.join("lagoon.svg");
+        let source = Some(InputSource::File(input.clone()));
+        export(Selection::Day(18), Part::One, source, &svg, 100)?;
+        let written = fs::read_to_string(&svg).map_err(AocError::from)?;
+        assert!(written.contains("<title>lagoon of 9 cubic meters</title>"));
+
+        let png = dir.0.join("lagoon.png");
+        let source = Some(InputSource::File(input));
+        export(Selection::Day(18), Part::Two, source, &png, 100)?;
+        let written = fs::read(&png).map_err(AocError::from)?;
+        assert!(written.starts_with(b"\x89PNG"));
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_days_without_pictures() {
+        let error = export(
+            Selection::Day(1),
+            Part::One,
+            None,
+            Path::new("out.svg"),
+            100,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.downcast_ref(),
+            Some(AocError::NoExport { day: 1, .. })
+        ));
+    }
+}
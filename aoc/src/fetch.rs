@@ -0,0 +1,245 @@
+use crate::answers;
+use crate::api::{Client, YEAR};
+use crate::custom_error::AocError;
+use miette::Context;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where downloaded inputs are kept so each one is only requested once.
+pub fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("aoc")
+}
+
+/// Where an input came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Cache,
+    Download,
+}
+
+#[derive(Debug)]
+pub struct Fetch<'a> {
+    pub base_url: &'a str,
+    pub session: Option<String>,
+    pub cache_dir: &'a Path,
+    /// Downloads again even when the input is already cached.
+    pub refresh: bool,
+}
+
+impl Fetch<'_> {
+    fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(YEAR.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    /// The input of `day`, from the cache if possible. Downloads are cached for next time.
+    #[tracing::instrument(skip(self))]
+    pub fn input(&self, day: u8) -> miette::Result<(String, Origin)> {
+        let cached = self.cache_path(day);
+        if !self.refresh {
+            if let Ok(input) = fs::read_to_string(&cached) {
+                return Ok((input, Origin::Cache));
+            }
+        }
+
+        let input = Client::new(self.base_url, self.session.clone())?.input(day)?;
+        write(&cached, &input)?;
+        Ok((input, Origin::Download))
+    }
+}
+
+/// Fetches the input of `day` into `input1.txt` and `input2.txt` of the day crate in `root`.
+#[tracing::instrument(skip(fetch))]
+pub fn fetch(fetch: &Fetch, day: u8, root: &Path) -> miette::Result<()> {
+    let dir = root.join(format!("day-{day:02}"));
+    if !dir.is_dir() {
+        return Err(AocError::MissingDayCrate { day, dir }.into());
+    }
+
+    let (input, origin) = fetch.input(day)?;
+    for name in ["input1.txt", "input2.txt"] {
+        write(&dir.join(name), &input)?;
+    }
+
+    let origin = match origin {
+        Origin::Cache => "from the cache",
+        Origin::Download => "downloaded",
+    };
+    println!(
+        "day {day:02}: {} bytes {origin} into {}",
+        input.len(),
+        dir.display()
+    );
+    Ok(())
+}
+
+/// The repository root, where `just create` puts the day crates.
+pub fn default_root() -> PathBuf {
+    answers::root()
+}
+
+fn write(path: &Path, contents: &str) -> miette::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(AocError::from)
+            .wrap_err_with(|| format!("creating {}", parent.display()))?;
+    }
+    fs::write(path, contents)
+        .map_err(AocError::from)
+        .wrap_err_with(|| format!("writing {}", path.display()))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// A scratch directory that is removed again when dropped.
+    pub(crate) struct TempDir(pub PathBuf);
+
+    impl TempDir {
+        pub(crate) fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Answers `requests` HTTP requests with `status` and `body`, returning each request's
+    /// head and body once the server is done.
+    pub(crate) fn serve(
+        requests: usize,
+        status: &'static str,
+        body: impl Into<String>,
+    ) -> (String, JoinHandle<Vec<String>>) {
+        let body = body.into();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            (0..requests)
+                .map(|_| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = String::new();
+                    let mut length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                            length = value.trim().parse().unwrap();
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut content = vec![0; length];
+                    std::io::Read::read_exact(&mut reader, &mut content).unwrap();
+                    request.push_str(&String::from_utf8(content).unwrap());
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+        (url, server)
+    }
+
+    #[test]
+    fn downloads_into_the_cache_and_the_day_crate() -> miette::Result<()> {
+        let root = TempDir::new("fetch-root");
+        let cache = TempDir::new("fetch-cache");
+        fs::create_dir(root.0.join("day-07")).unwrap();
+        let (url, server) = serve(1, "200 OK", "32T3K 765\n");
+
+        let fetch = Fetch {
+            base_url: &url,
+            session: Some("secret".to_string()),
+            cache_dir: &cache.0,
+            refresh: false,
+        };
+        super::fetch(&fetch, 7, &root.0)?;
+
+        let request = server.join().unwrap().remove(0);
+        assert!(request.starts_with("GET /2023/day/7/input HTTP/1.1\r\n"));
+        assert!(request.contains("session=secret"));
+        for name in ["input1.txt", "input2.txt"] {
+            assert_eq!(
+                "32T3K 765\n",
+                fs::read_to_string(root.0.join("day-07").join(name)).unwrap()
+            );
+        }
+
+        // The second fetch is served from the cache without a session.
+        let offline = Fetch {
+            session: None,
+            ..fetch
+        };
+        assert_eq!(
+            ("32T3K 765\n".to_string(), Origin::Cache),
+            offline.input(7)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn explains_rejected_sessions() {
+        let cache = TempDir::new("fetch-rejected");
+        let (url, server) = serve(1, "400 Bad Request", "");
+
+        let fetch = Fetch {
+            base_url: &url,
+            session: Some("expired".to_string()),
+            cache_dir: &cache.0,
+            refresh: true,
+        };
+        let err = fetch.input(1).unwrap_err();
+        server.join().unwrap();
+        assert!(err.to_string().contains("session was rejected"), "{err}");
+    }
+
+    #[test]
+    fn needs_a_session_to_download() {
+        let cache = TempDir::new("fetch-no-session");
+        let fetch = Fetch {
+            base_url: "http://127.0.0.1:9",
+            session: None,
+            cache_dir: &cache.0,
+            refresh: false,
+        };
+        assert!(matches!(
+            fetch.input(1).unwrap_err().downcast::<AocError>(),
+            Ok(AocError::MissingSession)
+        ));
+    }
+
+    #[test]
+    fn refuses_days_without_a_crate() {
+        let root = TempDir::new("fetch-missing");
+        let fetch = Fetch {
+            base_url: "http://127.0.0.1:9",
+            session: None,
+            cache_dir: &root.0,
+            refresh: false,
+        };
+        assert!(super::fetch(&fetch, 25, &root.0).is_err());
+    }
+}
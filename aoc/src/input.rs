@@ -0,0 +1,46 @@
+use crate::custom_error::AocError;
+use crate::days::Day;
+use aoc_core::Part;
+use miette::Context;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::{fs, io};
+
+/// Where a solver reads its puzzle input from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::File(PathBuf::from(path))),
+        }
+    }
+}
+
+impl InputSource {
+    pub fn read(&self) -> miette::Result<String> {
+        match self {
+            InputSource::Stdin => io::read_to_string(io::stdin())
+                .map_err(AocError::from)
+                .wrap_err("reading input from stdin"),
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(AocError::from)
+                .wrap_err_with(|| format!("reading input {}", path.display())),
+        }
+    }
+}
+
+/// Reads the input for `day`, preferring an explicitly given `input` over the bundled file.
+pub fn load(input: Option<&str>, day: &Day, part: Part) -> miette::Result<String> {
+    match input {
+        Some(input) => Ok(input.to_owned()),
+        None => InputSource::File(day.input_path(part)).read(),
+    }
+}
@@ -0,0 +1,15 @@
+pub mod answers;
+pub mod api;
+pub mod cli;
+pub mod custom_error;
+pub mod days;
+pub mod export;
+pub mod fetch;
+pub mod input;
+pub mod record;
+pub mod register;
+pub mod report;
+pub mod runner;
+pub mod submit;
+pub mod visualize;
+pub mod watch;
@@ -0,0 +1,139 @@
+use aoc::answers;
+use aoc::cli::{Cli, Command, Selection};
+use aoc::custom_error::AocError;
+use aoc::export;
+use aoc::fetch::{self, Fetch};
+use aoc::register;
+use aoc::report::{self, Format};
+use aoc::runner;
+use aoc::submit::{self, History, Submit};
+use aoc::watch;
+use clap::Parser;
+use miette::Context;
+use std::fs::File;
+use tracing_chrome::ChromeLayerBuilder;
+#[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+#[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
+use tracing_subscriber::Layer;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+fn main() -> miette::Result<()> {
+    let cli = Cli::parse();
+
+    // `report` profiles each part on its own, and only one profiler can run at a time.
+    #[cfg(feature = "dhat-heap")]
+    let _profiler = (!matches!(cli.command, Command::Report { .. })).then(dhat::Profiler::new_heap);
+
+    // Dropping the guard at the end of `main` flushes the rest of the trace to the file.
+    let (chrome, _trace_guard) = match &cli.trace {
+        Some(path) => {
+            let file = File::create(path)
+                .map_err(AocError::from)
+                .wrap_err_with(|| format!("creating trace file {}", path.display()))?;
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+    let registry = tracing_subscriber::registry().with(chrome);
+
+    #[cfg(feature = "tracy")]
+    let registry = registry.with(tracing_tracy::TracyLayer::default());
+
+    // Logs go to stderr so that stdout only carries answers, which `--format json` relies on.
+    #[cfg(not(any(feature = "dhat-heap", feature = "tracy")))]
+    let registry = registry.with(
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_filter(
+                EnvFilter::builder()
+                    .with_default_directive(LevelFilter::INFO.into())
+                    .from_env_lossy(),
+            ),
+    );
+
+    // dhat would count the subscriber's allocations too, so heap profiles only get one on request.
+    if !cfg!(feature = "dhat-heap") || cli.trace.is_some() {
+        registry.init();
+    }
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            visualize,
+        } => runner::run(day, part, input, format, visualize)?,
+        Command::Export {
+            day,
+            part,
+            output,
+            input,
+            size,
+        } => export::export(day, part, input, &output, size)?,
+        Command::Verify { day, answers, slow } => runner::verify(day, &answers, slow)?,
+        Command::Report {
+            day,
+            runs,
+            format,
+            readme,
+            slow,
+        } => {
+            let rows = report::measure(day, runs, slow)?;
+            match readme {
+                true => report::write_readme(
+                    &answers::root().join("README.md"),
+                    &report::render(&rows, Format::Markdown),
+                )?,
+                false => print!("{}", report::render(&rows, format)),
+            }
+        }
+        Command::Fetch { day, refresh, site } => {
+            let Selection::Day(day) = day else {
+                return Err(AocError::FetchAllDays.into());
+            };
+            let fetch = Fetch {
+                base_url: &site.base_url,
+                session: site.session,
+                cache_dir: &site.cache_dir,
+                refresh,
+            };
+            fetch::fetch(&fetch, day, &fetch::default_root())?;
+        }
+        Command::Submit { day, part, site } => {
+            let Selection::Day(day) = day else {
+                return Err(AocError::SubmitAllDays.into());
+            };
+            let submit = Submit {
+                base_url: &site.base_url,
+                session: site.session,
+                history: History::default_path(&site.cache_dir),
+            };
+            submit::submit(&submit, day, part)?;
+        }
+        Command::Register { day } => {
+            let Selection::Day(day) = day else {
+                return Err(AocError::RegisterAllDays.into());
+            };
+            register::register(day, &fetch::default_root())?;
+        }
+        Command::Watch { day, part } => {
+            let Selection::Day(day) = day else {
+                return Err(AocError::WatchAllDays.into());
+            };
+            watch::watch(day, part)?;
+        }
+        Command::Gen { day, size, seed } => println!("{}", runner::generate(day, size, seed)?),
+    }
+    Ok(())
+}
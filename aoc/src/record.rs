@@ -0,0 +1,136 @@
+use aoc_core::{Answer, Part};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// How `run` prints the outcome of each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// The outcome of one part of one day, as `run --format json` prints it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: Option<u128>,
+    /// Tells apart runs on different inputs without publishing the inputs themselves.
+    pub input_sha256: Option<String>,
+    pub error: Option<Error>,
+}
+
+/// A failure reduced to its diagnostic code, so scripts need not match on rendered text, and
+/// a message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Error {
+    pub code: Option<String>,
+    /// Every cause, outermost first, joined by `: `.
+    pub message: String,
+}
+
+impl Record {
+    /// `input` is `None` when it could not be read, which `result` then reports.
+    pub fn new(day: u8, part: Part, input: Option<&str>, result: &miette::Result<Answer>) -> Self {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(report) => (None, Some(Error::from(report))),
+        };
+
+        Record {
+            day,
+            part: part.number(),
+            answer: answer.map(|answer| answer.value.clone()),
+            parse_ns: answer.map(|answer| answer.parse.as_nanos()),
+            solve_ns: answer.map(|answer| answer.solve.as_nanos()),
+            input_sha256: input.map(sha256),
+            error,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records serialize")
+    }
+}
+
+impl From<&miette::Report> for Error {
+    fn from(report: &miette::Report) -> Self {
+        Error {
+            code: report.code().map(|code| code.to_string()),
+            message: report
+                .chain()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(": "),
+        }
+    }
+}
+
+/// The hex-encoded SHA-256 digest of `input`.
+pub fn sha256(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_error::AocError;
+    use crate::days;
+    use crate::runner;
+
+    #[test]
+    fn hashes_the_input() {
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            sha256("abc")
+        );
+    }
+
+    #[test]
+    fn records_solved_parts() -> miette::Result<()> {
+        let day = days::find(1).ok_or(AocError::UnknownDay(1))?;
+        let input = "1abc2\npqr3stu8vwx";
+        let record = Record::new(
+            1,
+            Part::One,
+            Some(input),
+            &runner::solve(day, input, Part::One),
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&record.to_json()).map_err(|e| miette::miette!(e))?;
+        assert_eq!("50", json["answer"]);
+        assert!(json["parse_ns"].is_u64());
+        assert_eq!(sha256(input), json["input_sha256"]);
+        assert!(json["error"].is_null());
+        Ok(())
+    }
+
+    #[test]
+    fn records_the_diagnostic_code_of_failures() -> miette::Result<()> {
+        let day = days::find(2).ok_or(AocError::UnknownDay(2))?;
+        let record = Record::new(
+            2,
+            Part::One,
+            Some("Game"),
+            &runner::solve(day, "Game", Part::One),
+        );
+
+        assert_eq!(None, record.answer);
+        assert_eq!(None, record.solve_ns);
+        let error = record.error.expect("the input is malformed");
+        assert_eq!(Some("aoc::parse_error"), error.code.as_deref());
+
+        let unreadable = Record::new(2, Part::One, None, &Err(AocError::InputForAllDays.into()));
+        assert_eq!(None, unreadable.input_sha256);
+        assert_eq!(
+            Some("aoc::invalid_input"),
+            unreadable.error.and_then(|error| error.code).as_deref()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use crate::custom_error::AocError;
+use miette::Context;
+use std::fs;
+use std::path::Path;
+
+/// One line to add to a file, after the last line that starts with `after`.
+struct Insert {
+    file: &'static str,
+    after: &'static str,
+    line: String,
+}
+
+/// Wires the crate of a freshly scaffolded day into the runner, the WebAssembly build and the
+/// fuzz targets. Files that already mention the day are left alone, so running it twice is fine.
+#[tracing::instrument]
+pub fn register(day: u8, root: &Path) -> miette::Result<()> {
+    let dir = root.join(format!("day-{day:02}"));
+    if !dir.is_dir() {
+        return Err(AocError::MissingDayCrate { day, dir }.into());
+    }
+
+    let dependency = format!("day-{day:02} = {{ path = \"../day-{day:02}\" }}");
+    let inserts = [
+        Insert {
+            file: "aoc/Cargo.toml",
+            after: "day-",
+            line: dependency.clone(),
+        },
+        Insert {
+            file: "aoc/Cargo.toml",
+            after: "    \"day-",
+            line: format!("    \"day-{day:02}/tracy\","),
+        },
+        Insert {
+            file: "aoc/src/days.rs",
+            after: "    day_",
+            line: format!("    day_{day:02}::Day{day:02}, \"day-{day:02}\";"),
+        },
+        Insert {
+            file: "aoc-wasm/Cargo.toml",
+            after: "day-",
+            line: dependency.clone(),
+        },
+        Insert {
+            file: "aoc-wasm/src/lib.rs",
+            after: "    day_",
+            line: format!("    day_{day:02}::Day{day:02},"),
+        },
+        Insert {
+            file: "fuzz/Cargo.toml",
+            after: "day-",
+            line: dependency,
+        },
+    ];
+
+    for insert in inserts {
+        let path = root.join(insert.file);
+        let text = read(&path)?;
+        let updated = insert_after_last(&text, insert.after, &insert.line)
+            .ok_or_else(|| AocError::Unregistrable(path.clone()))?;
+        write(&path, &updated)?;
+    }
+
+    let fuzz = root.join("fuzz/Cargo.toml");
+    let text = read(&fuzz)?;
+    if !text.contains(&format!("name = \"day_{day:02}\"")) {
+        write(&fuzz, &format!("{text}{}", fuzz_bin(day)))?;
+    }
+
+    let target = root.join(format!("fuzz/fuzz_targets/day_{day:02}.rs"));
+    if !target.exists() {
+        write(&target, &fuzz_target(day))?;
+    }
+
+    println!("day {day:02}: registered with aoc, aoc-wasm and the fuzz targets");
+    Ok(())
+}
+
+/// `text` with `line` added after the last line starting with `after`, unchanged if it already
+/// has `line`, or `None` if no line starts with `after`.
+fn insert_after_last(text: &str, after: &str, line: &str) -> Option<String> {
+    if text.lines().any(|existing| existing == line) {
+        return Some(text.to_string());
+    }
+
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let last = lines
+        .iter()
+        .rposition(|existing| existing.starts_with(after))?;
+    lines.insert(last + 1, line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn fuzz_bin(day: u8) -> String {
+    format!(
+        "\n[[bin]]\n\
+         name = \"day_{day:02}\"\n\
+         path = \"fuzz_targets/day_{day:02}.rs\"\n\
+         test = false\n\
+         doc = false\n\
+         bench = false\n"
+    )
+}
+
+fn fuzz_target(day: u8) -> String {
+    format!(
+        "#![no_main]\n\
+         \n\
+         use libfuzzer_sys::fuzz_target;\n\
+         \n\
+         fuzz_target!(|input: &str| {{\n    \
+             let _ = day_{day:02}::part1::process(input);\n    \
+             let _ = day_{day:02}::part2::process(input);\n\
+         }});\n"
+    )
+}
+
+fn read(path: &Path) -> miette::Result<String> {
+    fs::read_to_string(path)
+        .map_err(AocError::from)
+        .wrap_err_with(|| format!("reading {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> miette::Result<()> {
+    fs::write(path, contents)
+        .map_err(AocError::from)
+        .wrap_err_with(|| format!("writing {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+    use crate::fetch::tests::TempDir;
+
+    #[test]
+    fn inserts_after_the_last_matching_line() {
+        let days = "days! {\n    day_01::Day01, \"day-01\";\n    day_02::Day02, \"day-02\";\n}\n";
+        let line = "    day_03::Day03, \"day-03\";";
+        let updated = insert_after_last(days, "    day_", line).unwrap();
+
+        assert_eq!(
+            "days! {\n    day_01::Day01, \"day-01\";\n    day_02::Day02, \"day-02\";\n    \
+             day_03::Day03, \"day-03\";\n}\n",
+            updated
+        );
+        assert_eq!(
+            Some(updated.clone()),
+            insert_after_last(&updated, "    day_", line)
+        );
+        assert_eq!(
+            None,
+            insert_after_last("[dependencies]\n", "    day_", line)
+        );
+    }
+
+    #[test]
+    fn registers_a_day_everywhere_once() -> miette::Result<()> {
+        let root = TempDir::new("register");
+        for file in [
+            "aoc/Cargo.toml",
+            "aoc/src/days.rs",
+            "aoc-wasm/Cargo.toml",
+            "aoc-wasm/src/lib.rs",
+            "fuzz/Cargo.toml",
+        ] {
+            let path = root.0.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(answers::root().join(file), path).unwrap();
+        }
+        fs::create_dir_all(root.0.join("fuzz/fuzz_targets")).unwrap();
+        fs::create_dir(root.0.join("day-24")).unwrap();
+
+        register(24, &root.0)?;
+        let once = fs::read_to_string(root.0.join("aoc/Cargo.toml")).unwrap();
+        register(24, &root.0)?;
+        let twice = fs::read_to_string(root.0.join("aoc/Cargo.toml")).unwrap();
+
+        assert_eq!(once, twice);
+        assert!(once.contains("day-24 = { path = \"../day-24\" }"));
+        assert!(once.contains("    \"day-24/tracy\",\n]"));
+        let days = fs::read_to_string(root.0.join("aoc/src/days.rs")).unwrap();
+        assert!(days.contains("    day_24::Day24, \"day-24\";\n}"));
+        let fuzz = fs::read_to_string(root.0.join("fuzz/Cargo.toml")).unwrap();
+        assert_eq!(1, fuzz.matches("name = \"day_24\"").count());
+        assert!(root.0.join("fuzz/fuzz_targets/day_24.rs").exists());
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_days_without_a_crate() {
+        let root = TempDir::new("register-missing");
+        assert!(register(25, &root.0).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::answers;
+use crate::cli::Selection;
+use crate::custom_error::AocError;
+use crate::days::Day;
+use crate::input::{self, InputSource};
+use crate::runner::{self, select};
+use aoc_core::Part;
+use clap::ValueEnum;
+use miette::Context;
+use serde::Serialize;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+const README_START: &str = "<!-- report:start -->";
+const README_END: &str = "<!-- report:end -->";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Markdown,
+    Json,
+}
+
+/// The measurements for one part of one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Outcome {
+    Measured {
+        answer: String,
+        runs: usize,
+        #[serde(with = "nanos")]
+        min: Duration,
+        #[serde(with = "nanos")]
+        median: Duration,
+        #[serde(with = "nanos")]
+        max: Duration,
+        /// Peak heap usage in bytes, only known when built with `dhat-heap`.
+        peak_memory: Option<usize>,
+    },
+    Skipped {
+        reason: String,
+    },
+    Failed {
+        error: String,
+    },
+}
+
+/// Runs every selected part `runs` times, skipping parts marked `slow` in the answers file
+/// unless `slow` is set.
+#[tracing::instrument]
+pub fn measure(selection: Selection, runs: usize, slow: bool) -> miette::Result<Vec<Row>> {
+    let slow_parts = answers::load(&answers::default_path())?
+        .into_iter()
+        .filter(|expected| expected.slow)
+        .map(|expected| (expected.day, expected.part))
+        .collect::<Vec<_>>();
+    let mut rows = Vec::new();
+
+    for day in select(selection)? {
+        for part in Part::BOTH {
+            let outcome = if slow_parts.contains(&(day.number, part)) && !slow {
+                Outcome::Skipped {
+                    reason: "slow, use --slow".to_string(),
+                }
+            } else {
+                measure_part(day, part, runs.max(1))
+            };
+            rows.push(Row {
+                day: day.number,
+                part: part.number(),
+                outcome,
+            });
+        }
+    }
+    Ok(rows)
+}
+
+fn measure_part(day: &Day, part: Part, runs: usize) -> Outcome {
+    let input = match input::load(None, day, part) {
+        Ok(input) => input,
+        Err(report) => {
+            return Outcome::Failed {
+                error: report.to_string(),
+            }
+        }
+    };
+
+    let mut times = Vec::with_capacity(runs);
+    let mut answer = String::new();
+    for _ in 0..runs {
+        let start = Instant::now();
+        match runner::solve(day, &input, part) {
+            Ok(solved) => answer = solved.value,
+            Err(report) => {
+                return Outcome::Failed {
+                    error: report.to_string(),
+                }
+            }
+        }
+        times.push(start.elapsed());
+    }
+    times.sort();
+
+    // Profiling slows every allocation down, so the peak comes from one extra, untimed run.
+    let (_, peak_memory) = peak_memory(|| runner::solve(day, &input, part));
+
+    Outcome::Measured {
+        answer,
+        runs,
+        min: times[0],
+        median: times[times.len() / 2],
+        max: times[times.len() - 1],
+        peak_memory,
+    }
+}
+
+/// Runs `f` and reports the most heap it had allocated at any one time.
+#[cfg(feature = "dhat-heap")]
+fn peak_memory<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    let _profiler = dhat::Profiler::builder().testing().build();
+    let value = f();
+    (value, Some(dhat::HeapStats::get().max_bytes))
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+fn peak_memory<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    (f(), None)
+}
+
+pub fn render(rows: &[Row], format: Format) -> String {
+    match format {
+        Format::Markdown => markdown(rows),
+        Format::Json => serde_json::to_string_pretty(rows).expect("rows serialize") + "\n",
+    }
+}
+
+fn markdown(rows: &[Row]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Answer | Min | Median | Max | Peak memory |\n\
+         |----:|-----:|-------:|----:|-------:|----:|------------:|\n",
+    );
+
+    for row in rows {
+        let cells = match &row.outcome {
+            Outcome::Measured {
+                answer,
+                min,
+                median,
+                max,
+                peak_memory,
+                ..
+            } => format!(
+                "{} | {min:.2?} | {median:.2?} | {max:.2?} | {}",
+                cell(answer),
+                peak_memory.map_or("-".to_string(), bytes)
+            ),
+            Outcome::Skipped { reason } => format!("skipped ({}) | - | - | - | -", cell(reason)),
+            Outcome::Failed { error } => format!("failed: {} | - | - | - | -", cell(error)),
+        };
+        writeln!(table, "| {:02} | {} | {cells} |", row.day, row.part).unwrap();
+    }
+
+    let total = rows
+        .iter()
+        .filter_map(|row| match row.outcome {
+            Outcome::Measured { median, .. } => Some(median),
+            _ => None,
+        })
+        .sum::<Duration>();
+    writeln!(table, "\nTotal of the medians: {total:.2?}").unwrap();
+    table
+}
+
+/// `text` made safe for one table cell: a `|` would end the cell and a newline the row, as in
+/// the rendered diagnostics of failed parts.
+fn cell(text: &str) -> String {
+    text.trim_end()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes} B"),
+        1024..=1048575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+    }
+}
+
+/// Replaces the text between the report markers of the README at `path` with `report`.
+pub fn write_readme(path: &Path, report: &str) -> miette::Result<()> {
+    let readme = InputSource::File(path.to_path_buf()).read()?;
+    let (before, rest) = readme
+        .split_once(README_START)
+        .ok_or(AocError::MissingReportMarkers)?;
+    let (_, after) = rest
+        .split_once(README_END)
+        .ok_or(AocError::MissingReportMarkers)?;
+
+    fs::write(
+        path,
+        format!("{before}{README_START}\n{report}{README_END}{after}"),
+    )
+    .map_err(AocError::from)
+    .wrap_err_with(|| format!("writing {}", path.display()))
+}
+
+/// Durations as whole nanoseconds, which keeps the JSON easy to post-process.
+mod nanos {
+    use serde::Serializer;
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(duration.as_nanos())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row {
+                day: 5,
+                part: 1,
+                outcome: Outcome::Measured {
+                    answer: "35".to_string(),
+                    runs: 3,
+                    min: Duration::from_micros(10),
+                    median: Duration::from_micros(12),
+                    max: Duration::from_micros(20),
+                    peak_memory: Some(2048),
+                },
+            },
+            Row {
+                day: 5,
+                part: 2,
+                outcome: Outcome::Skipped {
+                    reason: "slow".to_string(),
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn renders_a_markdown_table() {
+        let table = render(&rows(), Format::Markdown);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(
+            "| 05 | 1 | 35 | 10.00µs | 12.00µs | 20.00µs | 2.0 KiB |",
+            lines[2]
+        );
+        assert_eq!("| 05 | 2 | skipped (slow) | - | - | - | - |", lines[3]);
+        assert_eq!("Total of the medians: 12.00µs", lines[5]);
+    }
+
+    #[test]
+    fn keeps_multi_line_failures_in_their_cell() {
+        let rows = [Row {
+            day: 7,
+            part: 1,
+            outcome: Outcome::Failed {
+                error: "parse error\n  × expected `|` or a digit\n".to_string(),
+            },
+        }];
+        let table = render(&rows, Format::Markdown);
+        assert_eq!(
+            "| 07 | 1 | failed: parse error<br>  × expected `\\|` or a digit | - | - | - | - |",
+            table.lines().nth(2).unwrap()
+        );
+    }
+
+    #[test]
+    fn renders_json() -> miette::Result<()> {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&rows(), Format::Json)).map_err(|e| miette::miette!(e))?;
+        assert_eq!("measured", json[0]["status"]);
+        assert_eq!(12_000, json[0]["median"]);
+        assert_eq!(2048, json[0]["peak_memory"]);
+        assert_eq!("skipped", json[1]["status"]);
+        Ok(())
+    }
+}
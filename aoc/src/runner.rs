@@ -0,0 +1,198 @@
+use crate::answers::{self, Expected};
+use crate::cli::Selection;
+use crate::custom_error::AocError;
+use crate::days::{self, Day, DAYS};
+use crate::input::{self, InputSource};
+use crate::record::{Format, Record};
+use crate::visualize::{self, Frames};
+use aoc_core::{Answer, Part};
+use std::panic;
+use std::path::Path;
+
+pub fn select(selection: Selection) -> Result<Vec<&'static Day>, AocError> {
+    match selection {
+        Selection::All => Ok(DAYS.iter().collect()),
+        Selection::Day(number) => days::find(number)
+            .map(|day| vec![day])
+            .ok_or(AocError::UnknownDay(number)),
+    }
+}
+
+#[tracing::instrument]
+pub fn run(
+    selection: Selection,
+    part: Option<Part>,
+    source: Option<InputSource>,
+    format: Format,
+    frames: Option<Frames>,
+) -> miette::Result<()> {
+    if source.is_some() && matches!(selection, Selection::All) {
+        return Err(AocError::InputForAllDays.into());
+    }
+    let days = select(selection)?;
+    if let (Some(_), [day]) = (frames, &days[..]) {
+        if day.visualize.is_none() {
+            return Err(AocError::NoVisualization {
+                day: day.number,
+                visualized: visualize::visualized(DAYS),
+            }
+            .into());
+        }
+    }
+
+    let parts = part.map_or(Part::BOTH.to_vec(), |part| vec![part]);
+    let explicit = match source.map(|source| source.read()).transpose() {
+        Ok(explicit) => explicit,
+        // No part can run without its input, but scripts still get a record for each of them.
+        Err(report) if format == Format::Json => {
+            let failed = Err(report);
+            for day in &days {
+                for &part in &parts {
+                    println!("{}", Record::new(day.number, part, None, &failed).to_json());
+                }
+            }
+            return Err(AocError::SolverFailed(days.len() * parts.len()).into());
+        }
+        Err(report) => return Err(report),
+    };
+    let mut failed = 0;
+
+    for day in days {
+        for &part in &parts {
+            let (input, result) = match input::load(explicit.as_deref(), day, part) {
+                Ok(input) => {
+                    let result = solve(day, &input, part);
+                    (Some(input), result)
+                }
+                Err(report) => (None, Err(report)),
+            };
+            if result.is_err() {
+                failed += 1;
+            }
+
+            match format {
+                Format::Text => print_text(day.number, part, &result),
+                Format::Json => println!(
+                    "{}",
+                    Record::new(day.number, part, input.as_deref(), &result).to_json()
+                ),
+            }
+
+            if let (Some(frames), Some(input), Ok(_)) = (frames, &input, &result) {
+                visualize::show(day, input, part, frames)?;
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        n => Err(AocError::SolverFailed(n).into()),
+    }
+}
+
+fn print_text(day: u8, part: Part, result: &miette::Result<Answer>) {
+    let label = format!("day {day:02} part {}", part.number());
+    match result {
+        Ok(answer) => println!(
+            "{label}: {} (parse {:?}, solve {:?})",
+            answer.value, answer.parse, answer.solve
+        ),
+        Err(report) => eprintln!("{label}: {report:?}"),
+    }
+}
+
+/// Runs one part of `day`, turning a panicking solver into an error.
+pub fn solve(day: &Day, input: &str, part: Part) -> miette::Result<Answer> {
+    panic::catch_unwind(|| (day.run)(input, part)).map_err(|_| AocError::SolverPanicked)?
+}
+
+/// Runs the solver an answers file entry refers to and compares its answer.
+pub fn check(expected: &Expected) -> miette::Result<Answer> {
+    let day = days::find(expected.day).ok_or(AocError::UnknownDay(expected.day))?;
+    let input = InputSource::File(expected.input_path()).read()?;
+    let answer = solve(day, &input, expected.part)?;
+
+    if answer.value != expected.answer {
+        return Err(AocError::WrongAnswer {
+            expected: expected.answer.clone(),
+            found: answer.value,
+        }
+        .into());
+    }
+    Ok(answer)
+}
+
+/// Checks every entry of the answers file at `path` for the selected days.
+#[tracing::instrument]
+pub fn verify(selection: Selection, path: &Path, slow: bool) -> miette::Result<()> {
+    if let Selection::Day(number) = selection {
+        days::find(number).ok_or(AocError::UnknownDay(number))?;
+    }
+
+    let entries = answers::load(path)?;
+    let mut mismatched = 0;
+
+    for expected in entries.iter().filter(|expected| match selection {
+        Selection::All => true,
+        Selection::Day(number) => expected.day == number,
+    }) {
+        let label = format!("day {:02} part {}", expected.day, expected.part);
+
+        if expected.slow && !slow {
+            println!("{label}: skipped (slow, use --slow)");
+            continue;
+        }
+        match check(expected) {
+            Ok(answer) => println!("{label}: ok {} (solve {:?})", answer.value, answer.solve),
+            Err(report) => {
+                mismatched += 1;
+                eprintln!("{label}: {report:?}");
+            }
+        }
+    }
+
+    match mismatched {
+        0 => Ok(()),
+        n => Err(AocError::VerifyFailed(n).into()),
+    }
+}
+
+/// A random input for a single day, the same for the same `size` and `seed`.
+pub fn generate(selection: Selection, size: usize, seed: u64) -> Result<String, AocError> {
+    let Selection::Day(number) = selection else {
+        return Err(AocError::GenerateAllDays);
+    };
+    let day = days::find(number).ok_or(AocError::UnknownDay(number))?;
+    Ok((day.generate)(size, seed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_day_solves_its_generated_input() -> miette::Result<()> {
+        for day in DAYS {
+            for size in [5, 40] {
+                let input = generate(Selection::Day(day.number), size, 1)?;
+                assert_eq!(input, generate(Selection::Day(day.number), size, 1)?);
+                for part in Part::BOTH {
+                    solve(day, &input, part)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generates_one_day_at_a_time() {
+        assert!(matches!(
+            generate(Selection::All, 20, 1),
+            Err(AocError::GenerateAllDays)
+        ));
+        assert!(matches!(
+            generate(Selection::Day(26), 20, 1),
+            Err(AocError::UnknownDay(26))
+        ));
+    }
+}
@@ -0,0 +1,391 @@
+use crate::api::{Client, YEAR};
+use crate::custom_error::AocError;
+use crate::days;
+use crate::input;
+use crate::runner;
+use aoc_core::Part;
+use miette::Context;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What adventofcode.com made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+    /// The part was solved before, so the answer was not checked.
+    AlreadySolved,
+    /// Answers are rate limited; this one was not checked either.
+    Wait {
+        seconds: u64,
+    },
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page returned for a submission.
+    pub fn from_page(page: &str) -> Result<Self, AocError> {
+        let text = article_text(page);
+        let lower = text.to_lowercase();
+
+        let verdict = if lower.contains("that's the right answer") {
+            Verdict::Correct
+        } else if lower.contains("you gave an answer too recently") {
+            Verdict::Wait {
+                seconds: wait_seconds(&lower).unwrap_or(60),
+            }
+        } else if lower.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if lower.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if lower.contains("that's not the right answer") {
+            Verdict::Wrong
+        } else if lower.contains("don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            return Err(AocError::UnexpectedPage(text));
+        };
+        Ok(verdict)
+    }
+
+    /// Whether the answer was actually checked, and so is worth remembering.
+    fn is_final(self) -> bool {
+        !matches!(self, Verdict::AlreadySolved | Verdict::Wait { .. })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::AlreadySolved => write!(f, "not checked, the part is already solved"),
+            Verdict::Wait { seconds } => write!(f, "not checked, wait {seconds}s"),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, which holds the message, without any markup.
+fn article_text(page: &str) -> String {
+    let article = match page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        // Skips what is left of the opening tag, such as its attributes.
+        Some((article, _)) => article.split_once('>').map_or(article, |(_, body)| body),
+        None => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads `you have 1m 5s left to wait` as 65. The page says "you have to wait" earlier in the
+/// same message, so the wait is the text between the last "you have" and "left to wait".
+fn wait_seconds(text: &str) -> Option<u64> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("you have ")?;
+    wait.split_whitespace()
+        .map(|amount| {
+            if let Some(minutes) = amount.strip_suffix('m') {
+                minutes.parse::<u64>().ok().map(|m| m * 60)
+            } else {
+                amount.strip_suffix('s')?.parse().ok()
+            }
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer sent so far, so none is sent twice and known bounds are checked locally.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn default_path(cache_dir: &Path) -> PathBuf {
+        cache_dir.join(YEAR.to_string()).join("submissions.json")
+    }
+
+    /// Loads the history at `path`, which is empty before the first submission.
+    pub fn load(path: &Path) -> miette::Result<Self> {
+        let json = match fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => {
+                return Err(AocError::from(err))
+                    .wrap_err_with(|| format!("reading {}", path.display()))
+            }
+        };
+        serde_json::from_str(&json)
+            .map_err(|err| AocError::CorruptHistory(err.to_string()))
+            .wrap_err_with(|| format!("reading {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> miette::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(AocError::from)?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("history serializes");
+        fs::write(path, json)
+            .map_err(AocError::from)
+            .wrap_err_with(|| format!("writing {}", path.display()))
+    }
+
+    fn for_part(&self, day: u8, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.day == day && submission.part == part.number())
+    }
+
+    /// Rejects answers that are known to be wrong without asking the server.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<(), AocError> {
+        let previous = self.for_part(day, part).collect::<Vec<_>>();
+
+        if let Some(solved) = previous
+            .iter()
+            .find(|submission| submission.verdict == Verdict::Correct)
+        {
+            return Err(AocError::AlreadySolved {
+                answer: solved.answer.clone(),
+            });
+        }
+        if let Some(sent) = previous
+            .iter()
+            .find(|submission| submission.answer == answer)
+        {
+            return Err(AocError::AlreadySubmitted {
+                answer: sent.answer.clone(),
+                verdict: sent.verdict.to_string(),
+            });
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |verdict| {
+            previous
+                .iter()
+                .filter(move |submission| submission.verdict == verdict)
+                .filter_map(|submission| submission.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Verdict::TooHigh).min().filter(|&high| value >= high) {
+            return Err(AocError::OutOfBounds {
+                answer: answer.to_string(),
+                reason: format!("{high} was already too high"),
+            });
+        }
+        if let Some(low) = bound(Verdict::TooLow).max().filter(|&low| value <= low) {
+            return Err(AocError::OutOfBounds {
+                answer: answer.to_string(),
+                reason: format!("{low} was already too low"),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+}
+
+#[derive(Debug)]
+pub struct Submit<'a> {
+    pub base_url: &'a str,
+    pub session: Option<String>,
+    pub history: PathBuf,
+}
+
+impl Submit<'_> {
+    /// Sends `answer` unless the history already rules it out, and remembers the verdict.
+    #[tracing::instrument(skip(self))]
+    pub fn answer(&self, day: u8, part: Part, answer: &str) -> miette::Result<Verdict> {
+        let mut history = History::load(&self.history)?;
+        history.check(day, part, answer)?;
+
+        let page = Client::new(self.base_url, self.session.clone())?.answer(day, part, answer)?;
+        let verdict = Verdict::from_page(&page)?;
+
+        if verdict.is_final() {
+            history.record(Submission {
+                day,
+                part: part.number(),
+                answer: answer.to_string(),
+                verdict,
+            });
+            history.save(&self.history)?;
+        }
+        Ok(verdict)
+    }
+}
+
+/// Solves `part` of `day` on its bundled input and submits the answer.
+#[tracing::instrument(skip(submit))]
+pub fn submit(submit: &Submit, day: u8, part: Part) -> miette::Result<()> {
+    let solver = days::find(day).ok_or(AocError::UnknownDay(day))?;
+    let input = input::load(None, solver, part)?;
+    let answer = runner::solve(solver, &input, part)?.value;
+
+    let verdict = submit.answer(day, part, &answer)?;
+    println!("day {day:02} part {part}: {answer} is {verdict}");
+    match verdict {
+        Verdict::Correct => Ok(()),
+        verdict => Err(AocError::NotAccepted(verdict.to_string()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::{serve, TempDir};
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn reads_verdicts_from_the_page() -> Result<(), AocError> {
+        let verdict = |message| Verdict::from_page(&page(message));
+        assert_eq!(
+            Verdict::Correct,
+            verdict("That's the right answer! You are <em>one gold star</em> closer.")?
+        );
+        assert_eq!(
+            Verdict::TooHigh,
+            verdict("That's not the right answer; your answer is too high.")?
+        );
+        assert_eq!(
+            Verdict::TooLow,
+            verdict("That's not the right answer; your answer is too low.")?
+        );
+        assert_eq!(Verdict::Wrong, verdict("That's not the right answer.")?);
+        assert_eq!(
+            Verdict::Wait { seconds: 65 },
+            verdict("You gave an answer too recently. You have 1m 5s left to wait.")?
+        );
+        assert_eq!(
+            Verdict::AlreadySolved,
+            verdict("You don't seem to be solving the right level.")?
+        );
+        assert!(verdict("Something else entirely").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn reads_the_wait_from_the_whole_message() -> Result<(), AocError> {
+        let message = "You gave an answer too recently; you have to wait after submitting an \
+                       answer before trying again.  You have 36s left to wait.";
+        assert_eq!(Some(36), wait_seconds(&message.to_lowercase()));
+        assert_eq!(
+            Verdict::Wait { seconds: 36 },
+            Verdict::from_page(&page(message))?
+        );
+        assert_eq!(None, wait_seconds("you have 5é left to wait"));
+        Ok(())
+    }
+
+    fn history(submissions: &[(&str, Verdict)]) -> History {
+        History {
+            submissions: submissions
+                .iter()
+                .map(|&(answer, verdict)| Submission {
+                    day: 7,
+                    part: 1,
+                    answer: answer.to_string(),
+                    verdict,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn rules_out_known_wrong_answers() {
+        let history = history(&[("100", Verdict::TooHigh), ("10", Verdict::TooLow)]);
+        assert!(history.check(7, Part::One, "50").is_ok());
+        assert!(history.check(7, Part::One, "10").is_err());
+        assert!(history.check(7, Part::One, "100").is_err());
+        assert!(history.check(7, Part::One, "120").is_err());
+        assert!(history.check(7, Part::One, "5").is_err());
+        assert!(history.check(7, Part::Two, "120").is_ok());
+        assert!(history.check(8, Part::One, "120").is_ok());
+    }
+
+    #[test]
+    fn solved_parts_are_not_submitted_again() {
+        let history = history(&[("42", Verdict::Correct)]);
+        assert!(matches!(
+            history.check(7, Part::One, "43"),
+            Err(AocError::AlreadySolved { answer }) if answer == "42"
+        ));
+    }
+
+    #[test]
+    fn records_submissions_sent_to_the_server() -> miette::Result<()> {
+        let dir = TempDir::new("submit");
+        let too_high = page("That's not the right answer; your answer is too high.");
+        let (url, server) = serve(1, "200 OK", too_high);
+
+        let submit = Submit {
+            base_url: &url,
+            session: Some("secret".to_string()),
+            history: History::default_path(&dir.0),
+        };
+        assert_eq!(Verdict::TooHigh, submit.answer(7, Part::Two, "300")?);
+
+        let request = server.join().unwrap().remove(0);
+        assert!(request.starts_with("POST /2023/day/7/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("level=2&answer=300"), "{request}");
+
+        // Known to be too high, so this never reaches the (now stopped) server.
+        let err = submit.answer(7, Part::Two, "301").unwrap_err();
+        assert!(err.to_string().contains("too high"), "{err}");
+        Ok(())
+    }
+
+    #[test]
+    fn does_not_record_rate_limited_submissions() -> miette::Result<()> {
+        let dir = TempDir::new("submit-wait");
+        let wait = page("You gave an answer too recently. You have 30s left to wait.");
+        let (url, server) = serve(1, "200 OK", wait);
+
+        let submit = Submit {
+            base_url: &url,
+            session: Some("secret".to_string()),
+            history: History::default_path(&dir.0),
+        };
+        assert_eq!(
+            Verdict::Wait { seconds: 30 },
+            submit.answer(7, Part::One, "1")?
+        );
+        server.join().unwrap();
+
+        assert!(History::load(&submit.history)?
+            .check(7, Part::One, "1")
+            .is_ok());
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::days::Day;
+use aoc_core::Part;
+use aoc_visual::to_ansi;
+use clap::ValueEnum;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+use std::{env, thread};
+
+/// Which frames `run --visualize` draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Frames {
+    /// Only the state the answer is read from
+    Final,
+    /// Every frame on the way there too, played as an animation on a terminal
+    All,
+}
+
+/// How long each frame of an animation stays on the screen.
+const FRAME_TIME: Duration = Duration::from_millis(80);
+
+/// Clears the screen and moves the cursor to its top left corner.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Draws `part` of `day` for `input` on stdout, in colour unless `NO_COLOR` is set. Days that
+/// cannot be drawn are left out.
+pub fn show(day: &Day, input: &str, part: Part, frames: Frames) -> miette::Result<()> {
+    let Some(visualize) = day.visualize else {
+        return Ok(());
+    };
+    let color = env::var_os("NO_COLOR").is_none();
+    let animate = io::stdout().is_terminal();
+
+    let mut last = None;
+    visualize(input, part, &mut |scene| match frames {
+        Frames::Final => last = Some(scene),
+        Frames::All if animate => {
+            print!("{CLEAR}{}", to_ansi(&scene, color));
+            thread::sleep(FRAME_TIME);
+        }
+        Frames::All => println!("{}", to_ansi(&scene, color)),
+    })?;
+
+    if let Some(scene) = last {
+        print!("{}", to_ansi(&scene, color));
+    }
+    Ok(())
+}
+
+/// The numbers of the days that can be drawn, like `10, 14`.
+pub fn visualized(days: &[Day]) -> String {
+    days.iter()
+        .filter(|day| day.visualize.is_some())
+        .map(|day| day.number.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn lists_the_days_that_draw() {
+        assert_eq!("10, 14, 16, 17", visualized(DAYS));
+    }
+
+    #[test]
+    fn every_visualized_day_draws_its_generated_input() -> miette::Result<()> {
+        for day in DAYS {
+            let Some(visualize) = day.visualize else {
+                continue;
+            };
+            let input = (day.generate)(20, 1);
+            for part in Part::BOTH {
+                let mut frames = 0;
+                visualize(&input, part, &mut |_| frames += 1)?;
+                assert!(frames > 0, "day {} part {part} drew nothing", day.number);
+            }
+        }
+        Ok(())
+    }
+}
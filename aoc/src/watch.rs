@@ -0,0 +1,248 @@
+use crate::answers::{self, Expected};
+use crate::custom_error::AocError;
+use crate::days::{self, Day};
+use crate::record::Record;
+use aoc_core::Part;
+use miette::Context;
+use notify_debouncer_mini::new_debouncer;
+use notify_debouncer_mini::notify::RecursiveMode;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use std::{env, fs};
+
+/// How long the files have to stay untouched before a change counts, so that an editor saving
+/// several files at once only triggers one run.
+const SETTLE: Duration = Duration::from_millis(300);
+
+/// Re-runs the tests and solver of a day whenever its sources or inputs change, until killed.
+#[tracing::instrument]
+pub fn watch(number: u8, part: Option<Part>) -> miette::Result<()> {
+    let day = days::find(number).ok_or(AocError::UnknownDay(number))?;
+    let dir = fs::canonicalize(day.dir())
+        .map_err(AocError::from)
+        .wrap_err_with(|| format!("looking for day {number}'s crate"))?;
+    let expected = answers::load(&answers::default_path())?;
+
+    let (tx, rx) = mpsc::channel();
+    let mut debouncer = new_debouncer(SETTLE, tx).map_err(|e| AocError::Watch(e.to_string()))?;
+    debouncer
+        .watcher()
+        .watch(&dir, RecursiveMode::Recursive)
+        .map_err(|e| AocError::Watch(e.to_string()))?;
+
+    let mut previous = HashMap::new();
+    run_once(day, part, &expected, &mut previous);
+
+    for events in rx {
+        let events = events.map_err(|e| AocError::Watch(e.to_string()))?;
+        let changed = events
+            .iter()
+            .filter_map(|event| event.path.strip_prefix(&dir).ok())
+            .filter(|path| is_watched(path))
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            continue;
+        }
+
+        println!("\n{} changed", changed.join(", "));
+        run_once(day, part, &expected, &mut previous);
+    }
+    Ok(())
+}
+
+/// Whether a change to `path`, relative to the day's crate, calls for a new run.
+fn is_watched(path: &Path) -> bool {
+    path.starts_with("src") || matches!(path.to_str(), Some("input1.txt" | "input2.txt"))
+}
+
+/// Runs the day's tests and then its solver, both through cargo so that they see the latest
+/// sources, and prints how each answer compares.
+fn run_once(
+    day: &Day,
+    part: Option<Part>,
+    expected: &[Expected],
+    previous: &mut HashMap<u8, String>,
+) {
+    let package = format!("day-{:02}", day.number);
+    let mut test = cargo();
+    test.args(["test", "--quiet", "--package", &package]);
+    if let Some(part) = part {
+        test.arg(format!("part{part}"));
+    }
+    match test.status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => println!("{package}: tests failed"),
+        Err(e) => {
+            eprintln!("could not run cargo: {e}");
+            return;
+        }
+    }
+
+    let mut run = cargo();
+    run.args([
+        "run",
+        "--quiet",
+        "--release",
+        "--package",
+        "aoc",
+        "--",
+        "run",
+    ])
+    .arg(day.number.to_string())
+    .args(part.map(|part| part.to_string()))
+    .args(["--format", "json"])
+    .env("RUST_LOG", "off")
+    .stderr(Stdio::inherit());
+    let output = match run.output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("could not run cargo: {e}");
+            return;
+        }
+    };
+
+    for record in records(&String::from_utf8_lossy(&output.stdout)) {
+        let registered = expected
+            .iter()
+            .find(|expected| expected.day == record.day && expected.part.number() == record.part)
+            .map(|expected| expected.answer.as_str());
+
+        println!(
+            "{}",
+            describe(
+                &record,
+                previous.get(&record.part).map(String::as_str),
+                registered
+            )
+        );
+        if let Some(answer) = &record.answer {
+            previous.insert(record.part, answer.clone());
+        }
+    }
+}
+
+/// The records among the lines a run printed. Anything else is noise, which the run's stderr
+/// already shows if it matters.
+fn records(stdout: &str) -> Vec<Record> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+fn cargo() -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    command.current_dir(answers::root());
+    command
+}
+
+/// One line saying how a run's answer differs from the previous run and the registered answer.
+fn describe(record: &Record, previous: Option<&str>, expected: Option<&str>) -> String {
+    let label = format!("day {:02} part {}", record.day, record.part);
+    let Some(answer) = &record.answer else {
+        return match &record.error {
+            Some(error) => format!(
+                "{label}: failed with {}: {}",
+                error.code.as_deref().unwrap_or("no code"),
+                error.message
+            ),
+            None => format!("{label}: failed"),
+        };
+    };
+
+    let since = match previous {
+        None => "first run".to_string(),
+        Some(previous) if previous == answer => "unchanged".to_string(),
+        Some(previous) => format!("was {previous}{}", delta(previous, answer)),
+    };
+    let against = match expected {
+        None => "no registered answer".to_string(),
+        Some(expected) if expected == answer => "correct".to_string(),
+        Some(expected) => format!("expected {expected}{}", delta(expected, answer)),
+    };
+    format!("{label}: {answer} ({since}; {against})")
+}
+
+/// How far apart two numeric answers are, like `, +12`, or nothing for other answers.
+fn delta(from: &str, to: &str) -> String {
+    match (from.parse::<i128>(), to.parse::<i128>()) {
+        (Ok(from), Ok(to)) => to
+            .checked_sub(from)
+            .map_or(String::new(), |delta| format!(", {delta:+}")),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record;
+
+    fn record(answer: Option<&str>) -> Record {
+        Record {
+            day: 14,
+            part: 2,
+            answer: answer.map(str::to_string),
+            parse_ns: None,
+            solve_ns: None,
+            input_sha256: None,
+            error: answer.is_none().then(|| record::Error {
+                code: Some("aoc::parse_error".to_string()),
+                message: "unexpected `x`".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn watches_sources_and_inputs() {
+        assert!(is_watched(Path::new("src/part2.rs")));
+        assert!(is_watched(Path::new("input2.txt")));
+        assert!(!is_watched(Path::new("benches/bench.rs")));
+        assert!(!is_watched(Path::new("Cargo.toml")));
+    }
+
+    #[test]
+    fn compares_with_the_previous_and_registered_answers() {
+        assert_eq!(
+            "day 14 part 2: 64 (first run; no registered answer)",
+            describe(&record(Some("64")), None, None)
+        );
+        assert_eq!(
+            "day 14 part 2: 64 (unchanged; correct)",
+            describe(&record(Some("64")), Some("64"), Some("64"))
+        );
+        assert_eq!(
+            "day 14 part 2: 64 (was 70, -6; expected 52, +12)",
+            describe(&record(Some("64")), Some("70"), Some("52"))
+        );
+        assert_eq!(
+            "day 14 part 2: HPGD (was HPGE; correct)",
+            describe(&record(Some("HPGD")), Some("HPGE"), Some("HPGD"))
+        );
+    }
+
+    #[test]
+    fn skips_lines_that_are_not_records() {
+        let line = record(Some("64")).to_json();
+        let stdout = format!("AAA -> BBB\n{line}\n  Compiling day-14\n{line}\n");
+        assert_eq!(
+            vec![Some("64".to_string()); 2],
+            records(&stdout)
+                .into_iter()
+                .map(|record| record.answer)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn reports_failed_runs_by_code() {
+        assert_eq!(
+            "day 14 part 2: failed with aoc::parse_error: unexpected `x`",
+            describe(&record(None), Some("64"), Some("64"))
+        );
+    }
+}
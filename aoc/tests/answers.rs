@@ -0,0 +1,8 @@
+//! Runs every solver against its real input and compares it with `answers.txt`.
+
+use aoc::answers::Expected;
+use aoc::runner::check;
+use aoc_core::Part;
+use std::path::PathBuf;
+
+include!(concat!(env!("OUT_DIR"), "/answers.rs"));
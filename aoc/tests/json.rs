@@ -0,0 +1,50 @@
+//! Runs the binary with `run --format json`, whose stdout scripts read one record per line.
+
+use aoc::record::Record;
+use std::process::{Command, Output};
+
+/// Runs `aoc` with every log level enabled, so that any logging on stdout would show.
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env("RUST_LOG", "trace")
+        .output()
+        .expect("aoc runs")
+}
+
+/// Every line of stdout as a record, failing on any line that is not one.
+fn records(output: &Output) -> Vec<Record> {
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|error| panic!("{line:?}: {error}")))
+        .collect()
+}
+
+#[test]
+fn prints_only_records_on_stdout() {
+    // Day 08 logs every step it takes, and day 05 the ranges it maps.
+    for day in ["5", "8"] {
+        let output = aoc(&["run", day, "--format", "json"]);
+        let records = records(&output);
+        assert_eq!(2, records.len(), "day {day}");
+        assert!(records.iter().all(|record| record.error.is_none()));
+        assert!(!output.stderr.is_empty(), "day {day} should still log");
+    }
+}
+
+#[test]
+fn records_an_unreadable_input_for_every_part() {
+    let output = aoc(&["run", "3", "--input", "/nonexistent", "--format", "json"]);
+    assert!(!output.status.success());
+
+    let records = records(&output);
+    assert_eq!(
+        vec![1, 2],
+        records.iter().map(|record| record.part).collect::<Vec<_>>()
+    );
+    for record in records {
+        assert_eq!((3, None), (record.day, record.answer));
+        let error = record.error.expect("the read failed");
+        assert_eq!(Some("aoc::io_error"), error.code.as_deref());
+    }
+}
@@ -0,0 +1,35 @@
+//! Runs the binary with `--trace`, which writes a trace-event file for the whole run.
+
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn writes_the_trace_file() {
+    let path = std::env::temp_dir().join(format!("aoc-trace-{}.json", std::process::id()));
+    let status = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("--trace")
+        .arg(&path)
+        .args(["run", "1", "1"])
+        .status()
+        .expect("aoc runs");
+    assert!(status.success());
+
+    let trace = fs::read_to_string(&path).expect("the trace was written");
+    let _ = fs::remove_file(&path);
+    assert!(trace.starts_with('['), "{trace}");
+}
+
+#[test]
+fn reports_a_trace_file_it_cannot_create() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["--trace", "/nonexistent/dir/trace.json", "run", "1", "1"])
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("aoc runs");
+
+    // A diagnostic rather than a panic, which would exit with 101.
+    assert_eq!(Some(1), output.status.code());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("aoc::io_error"), "{stderr}");
+    assert!(stderr.contains("creating trace file"), "{stderr}");
+}
@@ -6,4 +6,10 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error("the answer does not fit in 64 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow,
 }
@@ -0,0 +1,17 @@
+use crate::Day{{day}};
+use aoc_core::{Generator, Rng};
+
+/// `size` rows of up to ten small numbers, for the stand-in solvers.
+impl Generator for Day{{day}} {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                (0..rng.range(1..=10))
+                    .map(|_| rng.range(-100i64..=100).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -1,4 +1,31 @@
+use crate::custom_error::AocError;
+use aoc_core::Solution;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
+mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{ day | plus: 0 }};
+
+    type Input = Vec<Vec<i64>>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(parse::rows(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_core::{parse_complete, ParseError, Span};
+use nom::character::complete::{self, line_ending, space1};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+/// The puzzle input as rows of numbers, one per line. The parsers work on spans, so a
+/// malformed input is reported with the offending text highlighted.
+pub(crate) fn rows(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_complete(input, separated_list1(line_ending, row))
+}
+
+/// A line of numbers separated by spaces, e.g. `3 -1 4`.
+fn row(input: Span) -> IResult<Span, Vec<i64>> {
+    separated_list1(space1, complete::i64)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rows_of_numbers() -> miette::Result<()> {
+        assert_eq!(vec![vec![3, -1, 4], vec![1]], rows("3 -1 4\n1\n")?);
+        Ok(())
+    }
+
+    #[test]
+    fn points_at_malformed_input() {
+        let err = rows("3 -1 4\n1 x").unwrap_err();
+        assert_eq!((2, 3), err.location());
+    }
+}
@@ -1,21 +1,32 @@
 use crate::custom_error::AocError;
+use crate::Day{{day}};
+use aoc_core::Solution;
 
-#[tracing::instrument]
-pub fn process(
-    _input: &str,
-) -> miette::Result<String, AocError> {
-    todo!("day 01 - part 1");
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> miette::Result<i64, AocError> {
+    solve(&Day{{day}}::parse(input)?)
+}
+
+/// A stand-in that adds up the largest number of each row, to be replaced by the actual puzzle.
+#[tracing::instrument(skip(rows))]
+pub fn solve(rows: &[Vec<i64>]) -> Result<i64, AocError> {
+    rows.iter()
+        .filter_map(|row| row.iter().max())
+        .try_fold(0i64, |sum, &n| sum.checked_add(n))
+        .ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+    #[rstest]
+    #[case("1 2 3", 3)]
+    #[case("1 2 3\n4 5 6", 9)]
+    #[case("-7 -8", -7)]
+    fn test_process(#[case] input: &str, #[case] expected: i64) -> miette::Result<()> {
+        assert_eq!(expected, process(input)?);
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,3 @@
-
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -7,4 +6,7 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
-}
\ No newline at end of file
+    #[error("no digit in line `{0}`")]
+    #[diagnostic(code(aoc::invalid_input))]
+    NoDigit(String),
+}
@@ -0,0 +1,47 @@
+use crate::Day01;
+use aoc_core::{Generator, Rng};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters, spelled out digits and digits, each with at least one digit.
+impl Generator for Day01 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let mut line = String::new();
+                for _ in 0..rng.range(1..=8) {
+                    match rng.range(0..=2) {
+                        0 => line.push(char::from(b'0' + rng.range(1..=9))),
+                        1 => line.push_str(rng.pick(&WORDS)),
+                        _ => {
+                            for _ in 0..rng.range(1..=5) {
+                                line.push(char::from(rng.range(b'a'..=b'z')));
+                            }
+                        }
+                    }
+                }
+
+                // Part 1 only counts real digits.
+                if !line.chars().any(|c| c.is_ascii_digit()) {
+                    let at = rng.range(0..=line.len());
+                    line.insert(at, char::from(b'0' + rng.range(1..=9)));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate;
+
+    #[test]
+    fn generates_a_line_per_size() {
+        assert_eq!(500, generate::<Day01>(500, 1).lines().count());
+    }
+}
@@ -1,4 +1,30 @@
+use crate::custom_error::AocError;
+use aoc_core::Solution;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
 pub mod part1;
 pub mod part2;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
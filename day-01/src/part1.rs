@@ -1,21 +1,25 @@
 use crate::custom_error::AocError;
+use crate::Day01;
+use aoc_core::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let output = input
-        .lines()
+    solve(&Day01::parse(input)?)
+}
+
+#[tracing::instrument(skip(lines))]
+pub fn solve(lines: &[String]) -> Result<u32, AocError> {
+    let output = lines
+        .iter()
         .map(|line| {
             let mut it = line.chars().filter_map(|c| c.to_digit(10));
-            let first = it.next().expect("there should be a digit");
+            let first = it
+                .next()
+                .ok_or_else(|| AocError::NoDigit(line.to_owned()))?;
 
-            match it.last() {
-                Some(last) => format!("{first}{last}"),
-                None => format!("{first}{first}"),
-            }
-            .parse::<u32>()
-            .expect("should be a valid number")
+            Ok(10 * first + it.next_back().unwrap_or(first))
         })
-        .sum::<u32>();
+        .sum::<Result<u32, AocError>>()?;
 
     Ok(output)
 }
@@ -33,4 +37,9 @@ treb7uchet";
         assert_eq!(142, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn lines_without_digits_are_rejected() {
+        assert!(process("1abc2\nabc").is_err());
+    }
 }
@@ -1,50 +1,52 @@
 use crate::custom_error::AocError;
-use std::iter::from_fn;
+use crate::Day01;
+use aoc_core::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let output = input.lines().map(parse_line).sum::<u32>();
+    solve(&Day01::parse(input)?)
+}
+
+#[tracing::instrument(skip(lines))]
+pub fn solve(lines: &[String]) -> Result<u32, AocError> {
+    let output = lines
+        .iter()
+        .map(|line| parse_line(line))
+        .sum::<Result<u32, _>>()?;
 
     Ok(output)
 }
 
-pub fn parse_line(line: &str) -> u32 {
-    let mut index = 0;
-    let iter = from_fn(move || {
+pub fn parse_line(line: &str) -> Result<u32, AocError> {
+    let iter = line.char_indices().map(|(index, c)| {
         let reduced = &line[index..];
-        let result = if reduced.starts_with("one") {
-            Some('1')
+        if reduced.starts_with("one") {
+            '1'
         } else if reduced.starts_with("two") {
-            Some('2')
+            '2'
         } else if reduced.starts_with("three") {
-            Some('3')
+            '3'
         } else if reduced.starts_with("four") {
-            Some('4')
+            '4'
         } else if reduced.starts_with("five") {
-            Some('5')
+            '5'
         } else if reduced.starts_with("six") {
-            Some('6')
+            '6'
         } else if reduced.starts_with("seven") {
-            Some('7')
+            '7'
         } else if reduced.starts_with("eight") {
-            Some('8')
+            '8'
         } else if reduced.starts_with("nine") {
-            Some('9')
+            '9'
         } else {
-            let result = reduced.chars().next();
-            result
-        };
-        index += 1;
-        result
+            c
+        }
     });
     let mut it = iter.filter_map(|c| c.to_digit(10));
-    let first = it.next().expect("there should be a digit");
-    match it.last() {
-        Some(last) => format!("{first}{last}"),
-        None => format!("{first}{first}"),
-    }
-    .parse::<u32>()
-    .expect("should be a valid number")
+    let first = it
+        .next()
+        .ok_or_else(|| AocError::NoDigit(line.to_owned()))?;
+    Ok(10 * first + it.next_back().unwrap_or(first))
 }
 
 #[cfg(test)]
@@ -63,4 +65,10 @@ zoneight234
         assert_eq!(281, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn skips_over_non_ascii_text() -> miette::Result<()> {
+        assert_eq!(19, process("ñone€nineé")?);
+        Ok(())
+    }
 }
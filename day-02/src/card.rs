@@ -12,9 +12,9 @@ impl FromStr for Card {
     type Err = GameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split_whitespace().collect();
-        let number = parts[0].parse()?;
-        let color = parts[1].parse()?;
+        let (number, color) = s.split_once(' ').ok_or(GameError::Color)?;
+        let number = number.parse()?;
+        let color = color.trim().parse()?;
 
         Ok(Card { number, color })
     }
@@ -1,7 +1,7 @@
 use crate::custom_error::GameError;
 use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum Color {
     Red,
     Green,
@@ -1,4 +1,3 @@
-use derive_more::From;
 use miette::Diagnostic;
 use std::num::ParseIntError;
 use thiserror::Error;
@@ -8,11 +7,24 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error("the answer does not fit in 32 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow,
 }
 
-#[derive(Debug, From)]
+#[derive(Error, Debug)]
 pub enum GameError {
-    Number(ParseIntError),
+    #[error("invalid number: {0}")]
+    Number(#[from] ParseIntError),
+    #[error("unknown color `{0}`")]
     InvalidColor(String),
+    #[error("expected `<count> <color>`")]
     Color,
+    #[error("too many cubes of one color")]
+    TooManyCubes,
+    #[error("expected `Game <id>: <sets>`")]
+    Game,
 }
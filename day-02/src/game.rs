@@ -30,9 +30,13 @@ impl FromStr for Game {
     type Err = GameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(':').collect();
-        let id = parts[0][5..].trim().parse()?;
-        let sets = parts[1]
+        let (game, sets) = s.split_once(':').ok_or(GameError::Game)?;
+        let id = game
+            .strip_prefix("Game ")
+            .ok_or(GameError::Game)?
+            .trim()
+            .parse()?;
+        let sets = sets
             .split(';')
             .map(|set| set.parse())
             .collect::<Result<Vec<Set>, _>>()?;
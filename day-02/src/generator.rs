@@ -0,0 +1,27 @@
+use crate::Day02;
+use aoc_core::{Generator, Rng};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// `size` games numbered from 1, each revealing up to six handfuls of cubes.
+impl Generator for Day02 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (1..=size)
+            .map(|id| {
+                let sets = (0..rng.range(1..=6))
+                    .map(|_| {
+                        let mut colors = COLORS;
+                        rng.shuffle(&mut colors);
+                        colors[..rng.range(1..=3)]
+                            .iter()
+                            .map(|color| format!("{} {color}", rng.range(1..=20)))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                    .collect::<Vec<_>>();
+                format!("Game {id}: {}", sets.join("; "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
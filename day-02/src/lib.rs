@@ -1,8 +1,14 @@
+use crate::custom_error::AocError;
+use crate::game::Game;
+use aoc_core::{ParseError, Solution};
+use std::fmt::Display;
+
 pub mod custom_error;
 
 mod card;
 mod color;
 mod game;
+mod generator;
 pub mod part1;
 pub mod part2;
 mod score;
@@ -11,3 +17,30 @@ mod set;
 const MAX_BLUE: u32 = 14;
 const MAX_GREEN: u32 = 13;
 const MAX_RED: u32 = 12;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.parse::<Game>()
+                    .map_err(|err| ParseError::at(input, line, err.to_string()))
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,16 +1,20 @@
 use crate::custom_error::AocError;
 use crate::game::Game;
+use crate::Day02;
+use aoc_core::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let result = input
-        .lines()
-        .map(|s| s.parse::<Game>().unwrap())
-        .filter(|game| game.is_possible())
-        .map(|game| game.id)
-        .sum::<u32>();
+    solve(&Day02::parse(input)?)
+}
 
-    Ok(result)
+#[tracing::instrument(skip(games))]
+pub fn solve(games: &[Game]) -> Result<u32, AocError> {
+    games
+        .iter()
+        .filter(|game| game.is_possible())
+        .try_fold(0u32, |sum, game| sum.checked_add(game.id))
+        .ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]
@@ -1,15 +1,19 @@
 use crate::custom_error::AocError;
 use crate::game::Game;
+use crate::Day02;
+use aoc_core::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let result = input
-        .lines()
-        .map(|s| s.parse::<Game>().unwrap())
-        .map(|game| game.minset().power())
-        .sum();
+    solve(&Day02::parse(input)?)
+}
 
-    Ok(result)
+#[tracing::instrument(skip(games))]
+pub fn solve(games: &[Game]) -> Result<u32, AocError> {
+    games
+        .iter()
+        .try_fold(0u32, |sum, game| sum.checked_add(game.minset().power()?))
+        .ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]
@@ -33,4 +37,10 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
         assert_eq!(48, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn overflowing_powers_are_rejected() {
+        assert!(process("Game 1: 100000 red, 100000 green, 1 blue").is_err());
+        assert!(process("Game 1: 4294967295 red, 1 red").is_err());
+    }
 }
@@ -6,7 +6,8 @@ pub struct Score {
 }
 
 impl Score {
-    pub fn power(&self) -> u32 {
-        self.red * self.green * self.blue
+    /// The product of the three counts, or `None` if it overflows.
+    pub fn power(&self) -> Option<u32> {
+        self.red.checked_mul(self.green)?.checked_mul(self.blue)
     }
 }
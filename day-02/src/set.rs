@@ -38,6 +38,13 @@ impl FromStr for Set {
             .map(|card| card.trim().parse())
             .collect::<Result<Vec<Card>, _>>()?;
 
+        for color in [Color::Red, Color::Green, Color::Blue] {
+            draw.iter()
+                .filter(|card| card.color == color)
+                .try_fold(0u32, |total, card| total.checked_add(card.number))
+                .ok_or(GameError::TooManyCubes)?;
+        }
+
         Ok(Set { draw })
     }
 }
@@ -6,6 +6,15 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    GridError(#[from] aoc_grid::GridError),
+    #[error("the answer does not fit in 32 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow,
 }
 
 #[derive(Error, Debug)]
@@ -0,0 +1,46 @@
+use crate::Day03;
+use aoc_core::{Generator, Rng};
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+/// A `size` by `size` schematic of part numbers of up to three digits and sparse symbols,
+/// gears (`*`) being the most common.
+impl Generator for Day03 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let mut row = String::with_capacity(size);
+                while row.len() < size {
+                    let after_number = row.ends_with(|c: char| c.is_ascii_digit());
+                    if !after_number && rng.chance(0.15) {
+                        let digits = rng.range(1..=3).min(size - row.len());
+                        let number =
+                            rng.range(10u32.pow(digits as u32 - 1)..=10u32.pow(digits as u32) - 1);
+                        row.push_str(&number.to_string());
+                    } else if rng.chance(0.08) {
+                        row.push(match rng.chance(0.4) {
+                            true => '*',
+                            false => *rng.pick(&SYMBOLS),
+                        });
+                    } else {
+                        row.push('.');
+                    }
+                }
+                row
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate;
+
+    #[test]
+    fn generates_a_square_schematic() {
+        let input = generate::<Day03>(140, 3);
+        assert!(input.lines().all(|line| line.len() == 140));
+    }
+}
@@ -1,32 +1,58 @@
+use crate::custom_error::AocError;
 use crate::number::Number;
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Grid, Point};
 use nom::character::complete::{digit1, multispace1};
 use nom::multi::separated_list0;
 use nom::IResult;
 use regex::Regex;
+use std::fmt::Display;
 
 pub mod custom_error;
 
+mod generator;
 mod number;
 pub mod part1;
 pub mod part2;
 mod tests;
 
-pub fn extract_symbols(input: &str) -> Vec<(char, usize, usize)> {
-    let symbols = input
-        .lines()
-        .enumerate()
-        .flat_map(|(line, line_str)| {
-            line_str
-                .chars()
-                .enumerate()
-                .filter(|(_, c)| !c.is_ascii_digit() && *c != '.')
-                .map(move |(col, c)| (c, line, col))
+pub struct Day03;
+
+pub struct Schematic {
+    symbols: Vec<(char, Point)>,
+    numbers: Vec<Number>,
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Schematic {
+            symbols: extract_symbols(&Grid::parse(input)?),
+            numbers: parse_digits(input)?,
         })
-        .collect::<Vec<_>>();
-    symbols
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
+
+pub fn extract_symbols(grid: &Grid<char>) -> Vec<(char, Point)> {
+    grid.enumerate()
+        .filter(|(_, c)| !c.is_ascii_digit() && **c != '.')
+        .map(|(point, c)| (*c, point))
+        .collect()
 }
 
-pub fn parse_digits(input: &str) -> Result<Vec<Number>, String> {
+pub fn parse_digits(input: &str) -> Result<Vec<Number>, ParseError> {
     let regex = Regex::new(r"\d+").unwrap();
     let lines = input.lines().collect::<Vec<_>>();
 
@@ -36,7 +62,7 @@ pub fn parse_digits(input: &str) -> Result<Vec<Number>, String> {
         .flat_map(|(line_index, line)| {
             regex
                 .find_iter(line)
-                .map(move |match_indices| number_from_match(match_indices, line_index))
+                .map(move |match_indices| number_from_match(input, match_indices, line_index))
         })
         .collect()
 }
@@ -45,19 +71,24 @@ pub fn parsing_numbers(input: &str) -> IResult<&str, Vec<&str>> {
     separated_list0(multispace1, digit1)(input)
 }
 
-fn number_from_match(match_indices: regex::Match, line_index: usize) -> Result<Number, String> {
+fn number_from_match(
+    input: &str,
+    match_indices: regex::Match,
+    line_index: usize,
+) -> Result<Number, ParseError> {
     let start = match_indices.start();
     let end = match_indices.end();
-    match match_indices.as_str().parse::<usize>() {
+    match match_indices.as_str().parse::<u32>() {
         Ok(value) => Ok(Number {
-            value: value as u32,
+            value,
             line: line_index,
             start,
             end: end - 1,
         }),
-        Err(_) => Err(format!(
-            "Failed to parse number: {}",
-            match_indices.as_str()
+        Err(_) => Err(ParseError::at(
+            input,
+            match_indices.as_str(),
+            "number does not fit in a u32",
         )),
     }
 }
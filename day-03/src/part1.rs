@@ -1,16 +1,21 @@
 use crate::custom_error::AocError;
 use crate::number::Number;
-use crate::{extract_symbols, parse_digits};
+use crate::{parse_digits, Day03, Schematic};
+use aoc_core::Solution;
+use aoc_grid::Point;
 use itertools::Itertools;
 
-pub fn collect_adjacents(input: &str, symbols: Vec<(char, usize, usize)>) -> Vec<Number> {
-    parse_digits(input)
-        .unwrap()
+pub fn collect_adjacents(input: &str, symbols: Vec<(char, Point)>) -> Vec<Number> {
+    adjacent_numbers(&parse_digits(input).unwrap(), &symbols)
+}
+
+fn adjacent_numbers(numbers: &[Number], symbols: &[(char, Point)]) -> Vec<Number> {
+    numbers
         .iter()
         .filter(|number| {
             symbols
                 .iter()
-                .any(|(_, line, col)| number.is_adjacent(*line, *col))
+                .any(|(_, symbol)| number.is_adjacent(*symbol))
         })
         .copied()
         .collect_vec()
@@ -18,8 +23,13 @@ pub fn collect_adjacents(input: &str, symbols: Vec<(char, usize, usize)>) -> Vec
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let x = collect_adjacents(input, extract_symbols(input))
+    solve(&Day03::parse(input)?)
+}
+
+#[tracing::instrument(skip(schematic))]
+pub fn solve(schematic: &Schematic) -> Result<u32, AocError> {
+    adjacent_numbers(&schematic.numbers, &schematic.symbols)
         .iter()
-        .fold(0u32, |acc, number| acc + number.value);
-    Ok(x)
+        .try_fold(0u32, |acc, number| acc.checked_add(number.value))
+        .ok_or(AocError::Overflow)
 }
@@ -1,24 +1,33 @@
 use crate::custom_error::AocError;
-use crate::{extract_symbols, parse_digits};
+use crate::{Day03, Schematic};
+use aoc_core::Solution;
 
-fn sum_gears(input: &str) -> u32 {
-    let numbers = parse_digits(input).unwrap();
-    extract_symbols(input)
+/// The sum of all gear ratios, or `None` if it does not fit in a `u32`.
+fn sum_gears(schematic: &Schematic) -> Option<u32> {
+    schematic
+        .symbols
         .iter()
-        .filter(|(c, _, _)| *c == '*')
-        .map(|(_, line, col)| {
-            numbers
+        .filter(|(c, _)| *c == '*')
+        .map(|(_, gear)| {
+            schematic
+                .numbers
                 .iter()
-                .filter(|number| number.is_adjacent(*line, *col))
+                .filter(|number| number.is_adjacent(*gear))
                 .map(|number| number.value)
                 .collect::<Vec<_>>()
         })
         .filter(|numbers| numbers.len() == 2)
-        .map(|numbers| numbers.iter().product::<u32>())
-        .sum()
+        .try_fold(0u32, |sum, numbers| {
+            sum.checked_add(numbers[0].checked_mul(numbers[1])?)
+        })
 }
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    Ok(sum_gears(input))
+    solve(&Day03::parse(input)?)
+}
+
+#[tracing::instrument(skip(schematic))]
+pub fn solve(schematic: &Schematic) -> Result<u32, AocError> {
+    sum_gears(schematic).ok_or(AocError::Overflow)
 }
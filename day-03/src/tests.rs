@@ -3,6 +3,7 @@
 mod tests {
     use crate::extract_symbols;
     use crate::part1::{collect_adjacents, process};
+    use aoc_grid::Grid;
 
     #[test]
     fn test_process() -> miette::Result<()> {
@@ -16,7 +17,8 @@ mod tests {
 ......755.
 ...$.*....
 .664.598..";
-        assert_eq!(8, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(8, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -41,7 +43,8 @@ mod tests {
         let input = "1.3
 .*.
 4.5";
-        assert_eq!(4, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(4, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -50,7 +53,8 @@ mod tests {
         let input = "1..
 .*.
 ...";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -59,7 +63,8 @@ mod tests {
         let input = "..1
 .*.
 ...";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -68,7 +73,8 @@ mod tests {
         let input = "...
 .*.
 1..";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -77,7 +83,8 @@ mod tests {
         let input = "...
 .*.
 ..1";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -86,7 +93,8 @@ mod tests {
         let input = "...
 1*.
 ...";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -95,7 +103,8 @@ mod tests {
         let input = "...
 .*1
 ...";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -104,7 +113,8 @@ mod tests {
         let input = ".1.
 .*.
 ...";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -113,7 +123,8 @@ mod tests {
         let input = "...
 .*.
 .1.";
-        assert_eq!(1, collect_adjacents(input, extract_symbols(input)).len());
+        let symbols = extract_symbols(&Grid::parse(input)?);
+        assert_eq!(1, collect_adjacents(input, symbols).len());
         Ok(())
     }
 
@@ -132,4 +143,9 @@ mod tests {
         assert_eq!(467835, crate::part2::process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn overflowing_gear_ratios_are_rejected() {
+        assert!(crate::part2::process("100000*100000").is_err());
+    }
 }
@@ -2,7 +2,7 @@ use crate::custom_error::CardError;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Card {
     pub winners: Vec<u32>,
     pub hand: Vec<u32>,
@@ -10,11 +10,12 @@ pub struct Card {
 }
 
 impl Card {
-    pub fn points(&self) -> u32 {
+    /// The card's worth, or `None` if it is too large to count.
+    pub fn points(&self) -> Option<u32> {
         let count = self.winning_count();
         match count {
-            0 => 0,
-            _ => 2_u32.pow(count as u32 - 1),
+            0 => Some(0),
+            _ => 2_u32.checked_pow(u32::try_from(count - 1).ok()?),
         }
     }
 
@@ -1,6 +1,4 @@
-use derive_more::From;
 use miette::Diagnostic;
-
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
@@ -8,25 +6,18 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error("the answer does not fit in 32 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow,
 }
 
-#[derive(Debug, From)]
+#[derive(Error, Debug)]
 pub enum CardError {
+    #[error("expected numbers only")]
     Number,
+    #[error("expected winning numbers and a hand separated by `|`")]
     Parts,
 }
-
-impl From<CardError> for AocError {
-    fn from(err: CardError) -> Self {
-        match err {
-            CardError::Number => AocError::IoError(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "Invalid number",
-            )),
-            CardError::Parts => AocError::IoError(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "Invalid parts",
-            )),
-        }
-    }
-}
@@ -0,0 +1,43 @@
+use crate::Day04;
+use aoc_core::{Generator, Rng};
+
+const WINNERS: usize = 10;
+const HAND: usize = 25;
+
+/// `size` scratchcards with ten winning numbers and 25 numbers you have, all below 100.
+///
+/// Most cards win nothing so the copies of part 2 stay countable, and no card wins copies of
+/// cards past the end of the table.
+impl Generator for Day04 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (1..=size)
+            .map(|id| {
+                let mut numbers = (1..100).collect::<Vec<u32>>();
+                rng.shuffle(&mut numbers);
+
+                let matches = match rng.chance(0.1) {
+                    true => rng.range(1..=WINNERS).min(size - id),
+                    false => 0,
+                };
+                let winners = &numbers[..WINNERS];
+                let mut hand = numbers[..matches]
+                    .iter()
+                    .chain(&numbers[WINNERS..WINNERS + HAND - matches])
+                    .copied()
+                    .collect::<Vec<_>>();
+                rng.shuffle(&mut hand);
+
+                format!("Card {id:3}: {} | {}", row(winners), row(&hand))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn row(numbers: &[u32]) -> String {
+    numbers
+        .iter()
+        .map(|n| format!("{n:2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
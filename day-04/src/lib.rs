@@ -1,5 +1,38 @@
+use crate::card::Card;
+use crate::custom_error::AocError;
+use aoc_core::{ParseError, Solution};
+use std::fmt::Display;
+
 pub mod custom_error;
 
 mod card;
+mod generator;
 pub mod part1;
 pub mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.parse::<Card>()
+                    .map_err(|err| ParseError::at(input, line, err.to_string()))
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,12 +1,19 @@
 use crate::card::Card;
 use crate::custom_error::AocError;
+use crate::Day04;
+use aoc_core::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    input.lines().try_fold(0, |acc, line| {
-        let card = line.parse::<Card>()?;
-        Ok(acc + card.points())
-    })
+    solve(&Day04::parse(input)?)
+}
+
+#[tracing::instrument(skip(cards))]
+pub fn solve(cards: &[Card]) -> Result<u32, AocError> {
+    cards
+        .iter()
+        .try_fold(0u32, |sum, card| sum.checked_add(card.points()?))
+        .ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]
@@ -34,4 +41,10 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
         assert_eq!(vec![83, 86, 6, 31, 17, 9, 48, 53], card.hand);
         Ok(())
     }
+
+    #[test]
+    fn overflowing_points_are_rejected() {
+        let hand = vec!["7"; 33].join(" ");
+        assert!(process(&format!("Card 1: 7 | {hand}")).is_err());
+    }
 }
@@ -1,23 +1,32 @@
 use crate::card::Card;
 use crate::custom_error::AocError;
+use crate::Day04;
+use aoc_core::Solution;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let mut cards = input
-        .lines()
-        .map(|line| line.parse::<Card>().unwrap())
-        .collect::<Vec<_>>();
+    solve(&Day04::parse(input)?)
+}
+
+#[tracing::instrument(skip(cards))]
+pub fn solve(cards: &[Card]) -> Result<u32, AocError> {
+    let mut cards = cards.to_vec();
     let winnings: Vec<_> = cards.iter().map(|card| card.winning_count()).collect();
     for (index, &n) in winnings.iter().enumerate() {
         let rep_factor = cards[index].repetitions;
         for i in index + 1..index + n + 1 {
             if let Some(card) = cards.get_mut(i) {
-                card.repetitions += rep_factor;
+                card.repetitions = card
+                    .repetitions
+                    .checked_add(rep_factor)
+                    .ok_or(AocError::Overflow)?;
             }
         }
     }
-    let total_cards = cards.iter().map(|card| card.repetitions).sum::<u32>();
-    Ok(total_cards)
+    cards
+        .iter()
+        .try_fold(0u32, |sum, card| sum.checked_add(card.repetitions))
+        .ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]
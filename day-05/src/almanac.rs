@@ -0,0 +1,72 @@
+use aoc_algo::{IntervalSet, RangeMap};
+use aoc_core::ParseError;
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<RangeMap<u64>>,
+}
+
+impl Almanac {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (seeds, maps) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(input, "expected maps after the seeds"))?;
+
+        let seeds = seeds
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| ParseError::at(input, seeds, "expected a line starting with `seeds: `"))?
+            .split_whitespace()
+            .map(|seed| number(input, seed))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            seeds,
+            maps: parse_maps(input, maps)?,
+        })
+    }
+
+    pub fn location(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |acc, map| map.get(acc))
+    }
+
+    /// The locations of all of `seeds`, found a range at a time.
+    pub fn locations(&self, seeds: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.maps
+            .iter()
+            .fold(seeds.clone(), |acc, map| map.image(&acc))
+    }
+}
+
+fn parse_maps(input: &str, s: &str) -> Result<Vec<RangeMap<u64>>, ParseError> {
+    s.trim()
+        .split("\n\n")
+        .map(|m| {
+            let categories = m
+                .lines()
+                .skip(1)
+                .map(|sd| match sd.split_whitespace().collect::<Vec<_>>()[..] {
+                    [d, s, n] => {
+                        let (d, s, n) = (number(input, d)?, number(input, s)?, number(input, n)?);
+                        match (s.checked_add(n), d.checked_add(n)) {
+                            (Some(source_end), Some(_)) => Ok((s..source_end, d)),
+                            _ => Err(ParseError::at(input, sd, "range does not fit in 64 bits")),
+                        }
+                    }
+                    _ => Err(ParseError::at(
+                        input,
+                        sd,
+                        "expected `<destination> <source> <length>`",
+                    )),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(RangeMap::new(categories))
+        })
+        .collect()
+}
+
+fn number(input: &str, value: &str) -> Result<u64, ParseError> {
+    value
+        .parse()
+        .map_err(|_| ParseError::at(input, value, "expected a number"))
+}
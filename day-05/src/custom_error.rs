@@ -6,4 +6,13 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error("the almanac lists no seeds")]
+    #[diagnostic(code(aoc::invalid_input))]
+    NoSeeds,
+    #[error("seed range {start} plus {length} does not fit in 64 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    SeedRangeOverflow { start: u64, length: u64 },
 }
@@ -0,0 +1,98 @@
+use crate::Day05;
+use aoc_core::{Generator, Rng};
+use std::collections::BTreeSet;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Every number the almanac deals with is below this.
+const END: u64 = 1 << 32;
+
+/// An almanac with `size` seed ranges and `size` ranges in each map.
+///
+/// The source ranges of a map never overlap, and neither do the destinations: the numbers
+/// are cut into consecutive segments, laid out again in shuffled order, and every other
+/// segment becomes a range.
+impl Generator for Day05 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(1);
+        let seeds = (0..size)
+            .map(|_| {
+                let length = rng.range(1..=10_000);
+                format!("{} {length}", rng.range(0..=END - length))
+            })
+            .collect::<Vec<_>>();
+
+        let mut almanac = format!("seeds: {}", seeds.join(" "));
+        for pair in CATEGORIES.windows(2) {
+            almanac.push_str(&format!("\n\n{}-to-{} map:", pair[0], pair[1]));
+            for (destination, source, length) in map(size, rng) {
+                almanac.push_str(&format!("\n{destination} {source} {length}"));
+            }
+        }
+        almanac
+    }
+}
+
+/// `size` ranges as `(destination, source, length)`.
+fn map(size: usize, rng: &mut Rng) -> Vec<(u64, u64, u64)> {
+    let mut cuts = BTreeSet::from([0, END]);
+    while cuts.len() < 2 * size + 2 {
+        cuts.insert(rng.range(1..=END - 1));
+    }
+    let segments = cuts
+        .iter()
+        .zip(cuts.iter().skip(1))
+        .map(|(&start, &end)| (start, end - start))
+        .collect::<Vec<_>>();
+
+    let mut order = (0..segments.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    let mut destinations = vec![0; segments.len()];
+    let mut next = 0;
+    for index in order {
+        destinations[index] = next;
+        next += segments[index].1;
+    }
+
+    let mut ranges = segments
+        .into_iter()
+        .zip(destinations)
+        .skip(rng.range(0..=1))
+        .step_by(2)
+        .map(|((source, length), destination)| (destination, source, length))
+        .collect::<Vec<_>>();
+    ranges.truncate(size);
+    rng.shuffle(&mut ranges);
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_and_destinations_do_not_overlap() {
+        let mut rng = Rng::new(5);
+        for _ in 0..20 {
+            let ranges = map(8, &mut rng);
+            assert_eq!(8, ranges.len());
+            let destinations = ranges.iter().map(|&(to, _, length)| (to, length));
+            let sources = ranges.iter().map(|&(_, from, length)| (from, length));
+            for spans in [destinations.collect::<Vec<_>>(), sources.collect()] {
+                let mut spans = spans;
+                spans.sort();
+                assert!(spans.windows(2).all(|w| w[0].0 + w[0].1 <= w[1].0));
+                assert!(spans.iter().all(|&(start, length)| start + length <= END));
+            }
+        }
+    }
+}
@@ -1,4 +1,32 @@
+use crate::almanac::Almanac;
+use crate::custom_error::AocError;
+use aoc_core::Solution;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod almanac;
+mod generator;
 pub mod part1;
 pub mod part2;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Almanac::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
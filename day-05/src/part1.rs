@@ -1,70 +1,28 @@
+use crate::almanac::Almanac;
 use crate::custom_error::AocError;
-use itertools::Itertools;
-use std::ops::Range;
+use crate::Day05;
+use aoc_core::Solution;
 use tracing::info;
 
-struct Map {
-    sd: Vec<(Range<u64>, Range<u64>)>,
-}
-
-impl Map {
-    fn from_categories(mut sd: Vec<(Range<u64>, Range<u64>)>) -> Self {
-        sd.sort_by_key(|(s, _)| s.start);
-        Self { sd }
-    }
-
-    fn get(&self, key: u64) -> u64 {
-        let index = self.sd.partition_point(|(s, _)| s.start <= key) as i32 - 1;
-
-        if index < 0 {
-            return key;
-        }
-
-        self.sd
-            .get(index as usize)
-            .and_then(|(s, d)| (key <= s.end).then_some(d.start + (key.saturating_sub(s.start))))
-            .unwrap_or(key)
-    }
-}
-
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (seeds, maps) = input.split_once("\n\n").unwrap();
-
-    let seeds = seeds.strip_prefix("seeds: ").unwrap();
-    let seeds = seeds.split_whitespace().map(|x| x.parse::<u64>().unwrap());
+    solve(&Day05::parse(input)?)
+}
 
-    let maps = parse_maps(maps);
-    let sol = seeds
+#[tracing::instrument(skip(almanac))]
+pub fn solve(almanac: &Almanac) -> Result<u64, AocError> {
+    let sol = almanac
+        .seeds
+        .iter()
         .inspect(|seed| info!("seed: {}", seed))
-        .map(|seed| maps.iter().fold(seed, |acc, map| map.get(acc)))
+        .map(|&seed| almanac.location(seed))
         .inspect(|loc| info!("result: {}", loc))
         .min()
-        .unwrap();
+        .ok_or(AocError::NoSeeds)?;
 
     Ok(sol)
 }
 
-fn parse_maps(s: &str) -> Vec<Map> {
-    s.trim()
-        .split("\n\n")
-        .map(|m| {
-            Map::from_categories(
-                m.lines()
-                    .skip(1)
-                    .map(|sd| {
-                        let sd_map = sd.split_whitespace();
-                        let sd_n = sd_map.take(3);
-                        let sd_n = sd_n.map(|x| x.parse::<u64>().unwrap());
-                        let [d, s, n]: [u64; 3] = sd_n.collect_vec().try_into().unwrap();
-                        (s..s + n, d..d + n)
-                    })
-                    .collect(),
-            )
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,7 +6,13 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
-    #[diagnostic(code(aoc::parse_error))]
-    #[error("Failed to parse input")]
-    ParseError,
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error("merged race number {0} does not fit in 64 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    MergeOverflow(String),
+    #[error("the product of the ways to win does not fit in 64 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    ProductOverflow,
 }
@@ -0,0 +1,52 @@
+use crate::Day06;
+use aoc_core::{Generator, Rng};
+
+const RACES: usize = 4;
+
+/// Four races of up to `size` milliseconds, each with a record that can be beaten.
+///
+/// Part 2 reads the sheet as one race with the digits run together, which only fits in a
+/// `u64` while `size` stays in the hundreds.
+impl Generator for Day06 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let longest = (size as u64).max(7);
+        let (times, distances): (Vec<_>, Vec<_>) = (0..RACES)
+            .map(|_| {
+                let time = rng.range(longest.div_ceil(2).max(7)..=longest);
+                let best = (time / 2) * (time - time / 2);
+                (time, rng.range(time..=best - 1))
+            })
+            .unzip();
+
+        let width = distances
+            .iter()
+            .map(|distance| distance.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let line = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>width$}"))
+                .collect::<Vec<_>>()
+                .join("   ")
+        };
+        format!(
+            "Time:      {}\nDistance:  {}",
+            line(&times),
+            line(&distances)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{generate, run, Part};
+
+    #[test]
+    fn every_race_can_be_won() -> miette::Result<()> {
+        let input = generate::<Day06>(40, 6);
+        assert_ne!("0", run::<Day06>(&input, Part::One)?.value);
+        Ok(())
+    }
+}
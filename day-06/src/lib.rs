@@ -1,4 +1,32 @@
+use crate::custom_error::AocError;
+use crate::race::Race;
+use aoc_core::Solution;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
 pub mod part1;
 pub mod part2;
+mod race;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Race>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(race::parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,55 +1,30 @@
 use crate::custom_error::AocError;
+use crate::race::Race;
+use crate::Day06;
+use aoc_core::Solution;
 use itertools::Itertools;
 
-#[derive(Debug)]
-struct Race {
-    time: u64,
-    distance: u64,
-}
-
-impl Race {
-    fn new(time: u64, distance: u64) -> Self {
-        Self { time, distance }
-    }
-
-    fn is_winning(&self, hold: u64) -> bool {
-        let remaining = self.time - hold;
-        let distance_covered = remaining * hold;
-
-        distance_covered > self.distance
-    }
-}
-
-fn parse_input(input: &str) -> Result<Race, AocError> {
-    let (times, distances) = input.split_once('\n').ok_or(AocError::ParseError)?;
-
-    let times = times
-        .strip_prefix("Time:")
-        .ok_or(AocError::ParseError)?
-        .trim();
-    let distances = distances
-        .strip_prefix("Distance:")
-        .ok_or(AocError::ParseError)?
-        .trim();
-
-    let time: u64 = times.split_whitespace().join("").parse().unwrap();
-    let distance = distances.split_whitespace().join("").parse().unwrap();
-
-    Ok(Race::new(time, distance))
+/// The sheet was a single race all along, its numbers split up by bad kerning.
+fn merge(races: &[Race]) -> Result<Race, AocError> {
+    let time = races.iter().map(|race| race.time).join("");
+    let distance = races.iter().map(|race| race.distance).join("");
+
+    Ok(Race::new(
+        time.parse().map_err(|_| AocError::MergeOverflow(time))?,
+        distance
+            .parse()
+            .map_err(|_| AocError::MergeOverflow(distance))?,
+    ))
 }
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let race = parse_input(input)?;
-
-    let mut wins = 0;
-    for hold in 0..=race.time {
-        if race.is_winning(hold) {
-            wins += 1;
-        }
-    }
+    solve(&Day06::parse(input)?)
+}
 
-    Ok(wins)
+#[tracing::instrument(skip(races))]
+pub fn solve(races: &[Race]) -> Result<u64, AocError> {
+    Ok(merge(races)?.wins())
 }
 
 #[cfg(test)]
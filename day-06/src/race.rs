@@ -0,0 +1,144 @@
+use aoc_core::ParseError;
+use std::iter::zip;
+
+#[derive(Debug)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    pub fn new(time: u64, distance: u64) -> Self {
+        Self { time, distance }
+    }
+
+    pub fn is_winning(&self, hold: u64) -> bool {
+        let remaining = self.time - hold;
+        let distance_covered = u128::from(remaining) * u128::from(hold);
+
+        distance_covered > u128::from(self.distance)
+    }
+
+    /// Holding for `hold` or for `time - hold` goes equally far, and further the closer to half
+    /// the time, so the winning holds are one run around the middle. Searches for its start.
+    pub fn wins(&self) -> u64 {
+        let middle = self.time / 2;
+        if !self.is_winning(middle) {
+            return 0;
+        }
+
+        let (mut low, mut high) = (0, middle);
+        while low < high {
+            let hold = low + (high - low) / 2;
+            match self.is_winning(hold) {
+                true => high = hold,
+                false => low = hold + 1,
+            }
+        }
+
+        self.time - 2 * low + 1
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (time_line, distance_line) = input
+        .split_once('\n')
+        .ok_or_else(|| ParseError::at_end(input, "expected a `Distance:` line"))?;
+    let distance_line = distance_line.trim_end();
+
+    let times = numbers(input, time_line, "Time:")?;
+    let distances = numbers(input, distance_line, "Distance:")?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::at(
+            input,
+            distance_line,
+            format!("expected {} distances, one per race", times.len()),
+        ));
+    }
+
+    Ok(zip(times, distances)
+        .map(|(time, distance)| Race::new(time, distance))
+        .collect())
+}
+
+/// The numbers on a sheet line that starts with `label`.
+fn numbers(input: &str, line: &str, label: &str) -> Result<Vec<u64>, ParseError> {
+    let values = line.strip_prefix(label).ok_or_else(|| {
+        ParseError::at(
+            input,
+            line,
+            format!("expected a line starting with `{label}`"),
+        )
+    })?;
+
+    values
+        .split_whitespace()
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| ParseError::at(input, value, "expected a number"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// The winning holds lie strictly between the roots of `hold * (time - hold) = distance`.
+    fn wins_by_formula(race: &Race) -> u64 {
+        let (time, distance) = (race.time as f64, race.distance as f64);
+        let discriminant = time * time - 4.0 * distance;
+        if discriminant < 0.0 {
+            return 0;
+        }
+
+        let root = discriminant.sqrt();
+        let first = ((time - root) / 2.0).floor() as i64 + 1;
+        let last = ((time + root) / 2.0).ceil() as i64 - 1;
+        (last - first + 1).max(0) as u64
+    }
+
+    /// Tries every hold, as `wins` did before it searched: too slow for real races, but
+    /// obviously right.
+    fn wins_by_counting(race: &Race) -> u64 {
+        (0..=race.time)
+            .filter(|&hold| race.is_winning(hold))
+            .count() as u64
+    }
+
+    /// Races around the break-even distance, including ones that cannot be won.
+    fn race() -> impl Strategy<Value = Race> {
+        races_up_to(10_000)
+    }
+
+    fn races_up_to(max_time: u64) -> impl Strategy<Value = Race> {
+        (0..max_time).prop_flat_map(|time| {
+            (Just(time), 0..=time * time / 4 + 10)
+                .prop_map(|(time, distance)| Race::new(time, distance))
+        })
+    }
+
+    #[test]
+    fn example_races() {
+        assert_eq!(4, Race::new(7, 9).wins());
+        assert_eq!(0, Race::new(2, 1).wins());
+        assert_eq!(71503, Race::new(71530, 940200).wins());
+        assert_eq!(1, Race::new(1 << 32, (1 << 62) - 1).wins());
+        assert_eq!(u64::MAX - 3, Race::new(u64::MAX, u64::MAX).wins());
+    }
+
+    proptest! {
+        #[test]
+        fn counting_agrees_with_the_quadratic_formula(race in race()) {
+            prop_assert_eq!(wins_by_formula(&race), race.wins());
+        }
+
+        #[test]
+        fn searching_agrees_with_counting_every_hold(race in races_up_to(1_000)) {
+            prop_assert_eq!(wins_by_counting(&race), race.wins());
+        }
+    }
+}
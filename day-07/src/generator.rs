@@ -0,0 +1,44 @@
+use crate::{Day07, HAND_SIZE, RANKS};
+use aoc_core::{Generator, Rng};
+use std::collections::HashSet;
+
+/// `size` distinct hands with bids up to 1000, so no two hands ever tie.
+///
+/// There are only 13⁵ different hands, which caps `size`.
+impl Generator for Day07 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let ranks = RANKS.chars().collect::<Vec<_>>();
+        let size = size.min(ranks.len().pow(HAND_SIZE as u32));
+
+        let mut seen = HashSet::new();
+        let mut hands = Vec::with_capacity(size);
+        while hands.len() < size {
+            let cards = (0..HAND_SIZE)
+                .map(|_| *rng.pick(&ranks))
+                .collect::<String>();
+            if seen.insert(cards.clone()) {
+                hands.push(format!("{cards} {}", rng.range(1..=1000)));
+            }
+        }
+        hands.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate;
+
+    #[test]
+    fn generates_distinct_hands() {
+        let input = generate::<Day07>(5000, 7);
+        assert_eq!(
+            5000,
+            input
+                .lines()
+                .map(|line| &line[..HAND_SIZE])
+                .collect::<HashSet<_>>()
+                .len()
+        );
+    }
+}
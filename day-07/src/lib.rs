@@ -1,4 +1,59 @@
+use crate::custom_error::AocError;
+use aoc_core::{check_chars, ParseError, Solution};
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
 pub mod part1;
 pub mod part2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    /// Each hand's cards with its bid; the card ranking differs between parts.
+    type Input = Vec<(String, u32)>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| parse_hand(input, line))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
+
+const RANKS: &str = "23456789TJQKA";
+const HAND_SIZE: usize = 5;
+
+fn parse_hand(input: &str, line: &str) -> Result<(String, u32), ParseError> {
+    let (cards, bid) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::at(input, line, "expected `<cards> <bid>`"))?;
+
+    check_chars(input, cards, |c| RANKS.contains(c), "unknown card rank")
+        .map_err(|err| err.with_label(format!("expected one of {RANKS}")))?;
+    if cards.len() != HAND_SIZE {
+        return Err(ParseError::at(
+            input,
+            cards,
+            format!("expected {HAND_SIZE} cards"),
+        ));
+    }
+
+    let bid = bid
+        .parse()
+        .map_err(|_| ParseError::at(input, bid, "expected a number"))?;
+
+    Ok((cards.to_owned(), bid))
+}
@@ -6,6 +6,8 @@ use std::ops::Deref;
 use itertools::Itertools;
 
 use crate::custom_error::AocError;
+use crate::Day07;
+use aoc_core::Solution;
 
 #[derive(Debug, Clone)]
 struct Card(char);
@@ -38,7 +40,7 @@ impl Card {
             'Q' => 12,
             'K' => 13,
             'A' => 14,
-            _ => panic!("Invalid card rank"),
+            _ => unreachable!("card ranks are checked while parsing"),
         }
     }
 }
@@ -162,26 +164,28 @@ impl Game {
         Self { hands }
     }
 
-    fn total_winnings(&self) -> u32 {
+    fn total_winnings(&self) -> u64 {
         self.hands
             .iter()
             .enumerate()
             .map(|(i, hand)| {
-                let multiplier = i as u32 + 1;
-                hand.bid * multiplier
+                let multiplier = i as u64 + 1;
+                u64::from(hand.bid) * multiplier
             })
             .sum()
     }
 }
 
 #[tracing::instrument(skip(input))]
-pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    let hands = input
-        .lines()
-        .map(|line| {
-            let (cards, bid) = line.split_once(' ').unwrap();
-            Hand::new(cards, bid.parse().unwrap())
-        })
+pub fn process(input: &str) -> miette::Result<u64, AocError> {
+    solve(&Day07::parse(input)?)
+}
+
+#[tracing::instrument(skip(hands))]
+pub fn solve(hands: &[(String, u32)]) -> Result<u64, AocError> {
+    let hands = hands
+        .iter()
+        .map(|(cards, bid)| Hand::new(cards, *bid))
         .sorted()
         .collect_vec();
 
@@ -6,7 +6,19 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
     #[error("Invalid input")]
     #[diagnostic(code(aoc::invalid_input))]
     InvalidInput,
+    #[error("there is no `{0}` node")]
+    #[diagnostic(code(aoc::invalid_input))]
+    MissingNode(&'static str),
+    #[error("the walk from `{0}` never reaches an end node")]
+    #[diagnostic(code(aoc::invalid_input))]
+    Unreachable(String),
+    #[error("the answer does not fit in 64 bits")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow,
 }
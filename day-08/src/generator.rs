@@ -0,0 +1,126 @@
+use crate::Day08;
+use aoc_core::{Generator, Rng};
+
+const GHOSTS: usize = 6;
+
+/// A network walked by six ghosts, with up to `size` steps between a ghost's start and end.
+///
+/// Like in the puzzle, every start node (`..A`) leads into a loop that passes exactly one end
+/// node (`..Z`), and the loop takes as many steps as the way in, so part 2's least common
+/// multiple is the answer. The first ghost walks from `AAA` to `ZZZ` for part 1. Each loop
+/// is two parallel chains, so whichever way the instructions turn, a ghost moves one step
+/// further along; the loop lengths are distinct primes.
+impl Generator for Day08 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut names = names(rng);
+        let mut starts = names_ending_in('A', "AAA", rng);
+        let mut ends = names_ending_in('Z', "ZZZ", rng);
+
+        // Every step beyond the first needs two plain nodes, and there are only so many names.
+        let longest = (names.len() / (2 * GHOSTS)).min(size.max(GHOSTS + 2));
+        let mut lengths = primes(longest);
+        rng.shuffle(&mut lengths);
+
+        let mut nodes = Vec::new();
+        for &length in &lengths {
+            let (start, end) = (starts.remove(0), ends.remove(0));
+            let chains = [0, 1].map(|_| names.split_off(names.len() - (length - 1)));
+
+            // After the start come the chains, then the end, which leads back to the chains.
+            for from in [&start, &end] {
+                nodes.push(node(from, &chains[0][0], &chains[1][0], rng));
+            }
+            for step in 0..length - 1 {
+                let (left, right) = match step + 1 < length - 1 {
+                    true => (&chains[0][step + 1], &chains[1][step + 1]),
+                    false => (&end, &end),
+                };
+                for chain in &chains {
+                    nodes.push(node(&chain[step], left, right, rng));
+                }
+            }
+        }
+        rng.shuffle(&mut nodes);
+
+        let instructions = (0..rng.range(1..=size.max(1)))
+            .map(|_| match rng.chance(0.5) {
+                true => 'L',
+                false => 'R',
+            })
+            .collect::<String>();
+        format!("{instructions}\n\n{}", nodes.join("\n"))
+    }
+}
+
+fn node(name: &str, left: &str, right: &str, rng: &mut Rng) -> String {
+    // Both ways lead on, in either order.
+    let (left, right) = match rng.chance(0.5) {
+        true => (left, right),
+        false => (right, left),
+    };
+    format!("{name} = ({left}, {right})")
+}
+
+/// Three letter names that end neither in `A` nor in `Z`, in random order.
+fn names(rng: &mut Rng) -> Vec<String> {
+    let mut names = letters()
+        .flat_map(|a| letters().flat_map(move |b| letters().map(move |c| [a, b, c])))
+        .filter(|name| !matches!(name[2], 'A' | 'Z'))
+        .map(String::from_iter)
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    names
+}
+
+/// Names ending in `last`, with `first` first and the others in random order.
+fn names_ending_in(last: char, first: &str, rng: &mut Rng) -> Vec<String> {
+    let mut names = letters()
+        .flat_map(|a| letters().map(move |b| String::from_iter([a, b, last])))
+        .filter(|name| name != first)
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    names.insert(0, first.to_string());
+    names
+}
+
+fn letters() -> impl Iterator<Item = char> + Clone {
+    'A'..='Z'
+}
+
+/// The primes up to and including `limit`, largest first, so the loops stay close to it.
+fn primes(limit: usize) -> Vec<usize> {
+    let mut sieve = vec![true; limit + 1];
+    let mut primes = Vec::new();
+    for n in 2..=limit {
+        if sieve[n] {
+            primes.push(n);
+            (n * n..=limit)
+                .step_by(n)
+                .for_each(|multiple| sieve[multiple] = false);
+        }
+    }
+    primes.reverse();
+    primes.truncate(GHOSTS);
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{generate, run, Part};
+
+    #[test]
+    fn ghosts_meet_after_the_product_of_the_loop_lengths() -> miette::Result<()> {
+        let input = generate::<Day08>(50, 8);
+        // The six largest primes up to 50.
+        let lengths = [47u64, 43, 41, 37, 31, 29];
+
+        let first = run::<Day08>(&input, Part::One)?.value.parse().unwrap();
+        assert!(lengths.contains(&first));
+        assert_eq!(
+            lengths.iter().product::<u64>().to_string(),
+            run::<Day08>(&input, Part::Two)?.value
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,32 @@
+use crate::custom_error::AocError;
+use crate::network::Network;
+use aoc_core::Solution;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
+mod network;
 pub mod part1;
 pub mod part2;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Network::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,82 @@
+use aoc_core::{check_chars, ParseError};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub value: String,
+    pub left: String,
+    pub right: String,
+}
+
+impl Node {
+    fn new(value: String, left: String, right: String) -> Self {
+        Self { value, left, right }
+    }
+}
+
+pub struct Network {
+    pub sequence: String,
+    pub nodes: HashMap<String, Node>,
+}
+
+impl Network {
+    /// How many steps a walk can take before it must have been at the same node at the same
+    /// point of the instructions twice, and so will only go round in circles.
+    pub fn steps(&self) -> u64 {
+        (self.nodes.len() * self.sequence.len()) as u64
+    }
+
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let (sequence, rest) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::at_end(input, "expected nodes after the instructions"))?;
+
+        if sequence.is_empty() {
+            return Err(ParseError::new(input, 0, 0, "expected `L` or `R`"));
+        }
+        check_chars(input, sequence, |c| "LR".contains(c), "expected `L` or `R`")?;
+
+        let lines = rest
+            .lines()
+            .map(|line| {
+                let node = parse_node(line).ok_or_else(|| {
+                    ParseError::at(input, line, "expected `<node> = (<left>, <right>)`")
+                })?;
+                Ok((line, node))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let nodes = lines
+            .iter()
+            .map(|(_, node)| (node.value.clone(), node.clone()))
+            .collect::<HashMap<_, _>>();
+
+        if let Some((line, _)) = lines
+            .iter()
+            .find(|(_, node)| !nodes.contains_key(&node.left) || !nodes.contains_key(&node.right))
+        {
+            return Err(ParseError::at(input, line, "points to an unknown node"));
+        }
+
+        Ok(Self {
+            sequence: sequence.to_string(),
+            nodes,
+        })
+    }
+}
+
+fn parse_node(line: &str) -> Option<Node> {
+    let (key, value) = line.split_once(" = ")?;
+    let (left, right) = value
+        .strip_prefix('(')?
+        .strip_suffix(')')?
+        .split_once(", ")?;
+    if [key, left, right].iter().any(|name| name.is_empty()) {
+        return None;
+    }
+
+    Some(Node::new(
+        key.to_string(),
+        left.to_string(),
+        right.to_string(),
+    ))
+}
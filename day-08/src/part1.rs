@@ -1,55 +1,39 @@
 use crate::custom_error::AocError;
-use std::collections::HashMap;
+use crate::network::Network;
+use crate::Day08;
+use aoc_core::Solution;
 use tracing::info;
 
 const START: &str = "AAA";
 const END: &str = "ZZZ";
 
-#[derive(Debug)]
-struct Node<'a> {
-    value: &'a str,
-    left: String,
-    right: String,
-}
-
-impl<'a> Node<'a> {
-    fn new(value: &'a str, left: String, right: String) -> Self {
-        Self { value, left, right }
-    }
-}
-
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (sequence, rest) = input.split_once("\n\n").unwrap();
+    solve(&Day08::parse(input)?)
+}
 
-    let x: HashMap<String, Node> = rest
-        .lines()
-        .map(|line| {
-            let (key, value) = line.split_once(" = ").unwrap();
-            let (left, right) = value.split_once(", ").unwrap();
-            info!(%key, %left, %right);
-            (
-                key.to_string(),
-                Node::new(key, left.replace('(', ""), right.replace(')', "")),
-            )
-        })
-        .collect();
+#[tracing::instrument(skip(network))]
+pub fn solve(network: &Network) -> Result<u64, AocError> {
+    let x = &network.nodes;
 
-    let start = x.get(START).unwrap();
+    let start = x.get(START).ok_or(AocError::MissingNode(START))?;
     let mut current = start;
-    let mut sequence = sequence.chars().cycle();
+    let mut sequence = network.sequence.chars().cycle();
     let mut count = 0;
 
     while current.value != END {
+        if count >= network.steps() {
+            return Err(AocError::Unreachable(START.to_owned()));
+        }
         count += 1;
         match sequence.next() {
             Some('L') => {
                 info!("{} -> {}", current.value, current.left);
-                current = x.get(&current.left).unwrap();
+                current = &x[&current.left];
             }
             Some('R') => {
                 info!("{} -> {}", current.value, current.right);
-                current = x.get(&current.right).unwrap();
+                current = &x[&current.right];
             }
             _ => {
                 return Err(AocError::InvalidInput);
@@ -74,4 +58,10 @@ ZZZ = (ZZZ, ZZZ)";
         assert_eq!(6, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn unreachable_ends_are_reported() {
+        assert!(process("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").is_err());
+        assert!(process("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").is_err());
+    }
 }
@@ -1,76 +1,62 @@
 use crate::custom_error::AocError;
+use crate::network::{Network, Node};
+use crate::Day08;
+use aoc_algo::lcm_all;
+use aoc_core::par::prelude::*;
+use aoc_core::Solution;
 use itertools::Itertools;
-use num::integer::lcm;
-use rayon::iter::IntoParallelRefIterator;
-use rayon::iter::ParallelIterator;
-use std::collections::HashMap;
 
 const START: char = 'A';
 const END: char = 'Z';
 
 fn is_start(key: &str) -> bool {
-    key.chars().last().unwrap() == START
+    key.ends_with(START)
 }
 
-fn is_end(key: &&str) -> bool {
-    key.chars().last().unwrap() != END
-}
-
-#[derive(Debug, Clone)]
-struct Node<'a> {
-    value: &'a str,
-    left: String,
-    right: String,
-}
-
-impl<'a> Node<'a> {
-    fn new(value: &'a str, left: String, right: String) -> Self {
-        Self { value, left, right }
-    }
+fn is_end(key: &str) -> bool {
+    !key.ends_with(END)
 }
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<u64, AocError> {
-    let (sequence, rest) = input.split_once("\n\n").unwrap();
-    let map = parse_input(rest);
-    let lcm = map
+    solve(&Day08::parse(input)?)
+}
+
+#[tracing::instrument(skip(network))]
+pub fn solve(network: &Network) -> Result<u64, AocError> {
+    let map = &network.nodes;
+    let steps = map
         .iter()
         .filter(|(key, _)| is_start(key))
         .map(|(_, value)| value)
-        .cloned()
         .collect_vec()
         .par_iter()
-        .map(|mut node| {
-            let mut inner_seq = sequence.chars().cycle();
-            let mut count = 0;
-            while is_end(&node.value) {
-                count += 1;
-                match inner_seq.next().unwrap() {
-                    'L' => node = map.get(&node.left).unwrap(),
-                    'R' => node = map.get(&node.right).unwrap(),
-                    _ => unreachable!("Invalid input"),
-                }
-            }
-            count
-        })
-        .reduce(|| 1, lcm);
+        .map(|&start| steps_to_end(network, start))
+        .collect::<Result<Vec<u64>, _>>()?;
 
-    Ok(lcm)
+    lcm_all(steps).ok_or(AocError::Overflow)
 }
 
-fn parse_input(rest: &str) -> HashMap<String, Node> {
-    let map: HashMap<String, Node> = rest
-        .lines()
-        .map(|line| {
-            let (key, value) = line.split_once(" = ").unwrap();
-            let (left, right) = value.split_once(", ").unwrap();
-            (
-                key.to_string(),
-                Node::new(key, left.replace('(', ""), right.replace(')', "")),
-            )
-        })
-        .collect();
-    map
+/// How many steps the ghost starting at `start` takes to reach an end node, in a span of its own
+/// so that traces show how the ghosts spread over the threads.
+#[tracing::instrument(skip_all, fields(start = %start.value))]
+fn steps_to_end(network: &Network, start: &Node) -> Result<u64, AocError> {
+    let map = &network.nodes;
+    let mut node = start;
+    let mut inner_seq = network.sequence.chars().cycle();
+    let mut count = 0;
+    while is_end(&node.value) {
+        if count >= network.steps() {
+            return Err(AocError::Unreachable(start.value.clone()));
+        }
+        count += 1;
+        match inner_seq.next() {
+            Some('L') => node = &map[&node.left],
+            Some('R') => node = &map[&node.right],
+            _ => return Err(AocError::InvalidInput),
+        }
+    }
+    Ok(count)
 }
 
 #[cfg(test)]
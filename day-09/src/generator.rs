@@ -0,0 +1,47 @@
+use crate::Day09;
+use aoc_core::{Generator, Rng};
+
+const LENGTH: i64 = 21;
+
+/// `size` histories of 21 values, each a polynomial of degree six at most, so the
+/// differences always run out to zeros before the history does.
+impl Generator for Day09 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                // The first value of each row of differences.
+                let firsts = (0..=rng.range(0..=6))
+                    .map(|_| rng.range(-5..=5))
+                    .collect::<Vec<i64>>();
+                (0..LENGTH)
+                    .map(|x| newton(&firsts, x).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The value at `x` of the sequence whose rows of differences start with `firsts`.
+fn newton(firsts: &[i64], x: i64) -> i64 {
+    let mut binomial = 1;
+    let mut value = 0;
+    for (k, first) in (0..).zip(firsts) {
+        value += first * binomial;
+        binomial = binomial * (x - k) / (k + 1);
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebuilds_the_sequence_from_its_differences() {
+        // 1 3 6 10 15 21 starts with 1, then differences 2 and 1.
+        let values = (0..6).map(|x| newton(&[1, 2, 1], x)).collect::<Vec<_>>();
+        assert_eq!(vec![1, 3, 6, 10, 15, 21], values);
+    }
+}
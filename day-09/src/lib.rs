@@ -1,7 +1,31 @@
-#![feature(let_chains)]
-#![feature(iter_map_windows)]
+use crate::custom_error::AocError;
+use crate::shared::Sequence;
+use aoc_core::Solution;
+use std::fmt::Display;
 
 pub mod custom_error;
+mod generator;
 pub mod part1;
 pub mod part2;
 pub mod shared;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Sequence::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,13 +1,21 @@
 use crate::custom_error::AocError;
-use crate::shared::Sequence;
+use crate::Day09;
+use aoc_algo::next_value;
+use aoc_core::Solution;
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<i32, AocError> {
-    Ok(Sequence::parse(input)
+    solve(&Day09::parse(input)?)
+}
+
+#[tracing::instrument(skip(sequences))]
+pub fn solve(sequences: &[Vec<i32>]) -> Result<i32, AocError> {
+    sequences
         .iter()
-        .map(|s| Sequence::process(s))
-        .map(|v| v.iter().map(|diff| diff.last().unwrap()).sum::<i32>())
-        .sum())
+        .map(|s| next_value(s).ok_or(AocError::Overflow))
+        .try_fold(0i32, |acc, next| {
+            acc.checked_add(next?).ok_or(AocError::Overflow)
+        })
 }
 
 #[cfg(test)]
@@ -22,4 +30,10 @@ mod tests {
         assert_eq!(114, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn overflowing_differences_are_rejected() {
+        assert!(process("2147483647 -2147483648").is_err());
+        assert!(process("2147483647 2147483647\n1 1").is_err());
+    }
 }
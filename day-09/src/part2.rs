@@ -1,19 +1,21 @@
 use crate::custom_error::AocError;
-use crate::shared::Sequence;
+use crate::Day09;
+use aoc_algo::previous_value;
+use aoc_core::Solution;
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<i32, AocError> {
-    Ok(Sequence::parse(input)
+    solve(&Day09::parse(input)?)
+}
+
+#[tracing::instrument(skip(sequences))]
+pub fn solve(sequences: &[Vec<i32>]) -> Result<i32, AocError> {
+    sequences
         .iter()
-        .map(|s| Sequence::process(s))
-        .map(|v| {
-            v.iter()
-                .rev()
-                .skip(1)
-                .map(|diff| diff.first().unwrap())
-                .fold(0, |acc, &x| -acc + x)
+        .map(|s| previous_value(s).ok_or(AocError::Overflow))
+        .try_fold(0i32, |acc, next| {
+            acc.checked_add(next?).ok_or(AocError::Overflow)
         })
-        .sum())
 }
 
 #[cfg(test)]
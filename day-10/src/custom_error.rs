@@ -6,4 +6,13 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    GridError(#[from] aoc_grid::GridError),
+    #[error("the loop breaks off at column {x}, row {y}")]
+    #[diagnostic(code(aoc::invalid_input))]
+    BrokenLoop { x: i64, y: i64 },
 }
@@ -0,0 +1,224 @@
+use crate::Day10;
+use aoc_core::{Generator, Rng};
+use aoc_grid::{Grid, Point};
+use std::collections::HashMap;
+
+const JUNK: [char; 6] = ['|', '-', 'L', 'J', '7', 'F'];
+
+/// A `size` by `size` field of pipes with exactly one loop through the start tile.
+///
+/// The loop is the outline of a random blob of cells, drawn at twice the scale so it never
+/// touches itself. The tiles off the loop are junk, except that none of the start's
+/// neighbours connects to it unless it is part of the loop.
+impl Generator for Day10 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let side = size.max(5);
+        let tiles = outline(&blob((side - 3) / 2, rng));
+
+        let mut field = Grid::new(side, side, '.');
+        for point in field.positions().collect::<Vec<_>>() {
+            if rng.chance(0.6) {
+                field[point] = *rng.pick(&JUNK);
+            }
+        }
+        for (i, &point) in tiles.iter().enumerate() {
+            let before = tiles[(i + tiles.len() - 1) % tiles.len()];
+            let after = tiles[(i + 1) % tiles.len()];
+            field[point] = pipe(point, before, after);
+        }
+
+        let start = tiles[rng.below(tiles.len())];
+        for neighbour in field.neighbours4(start).collect::<Vec<_>>() {
+            if !tiles.contains(&neighbour) {
+                field[neighbour] = '.';
+            }
+        }
+        field[start] = 'S';
+
+        field
+            .rows()
+            .map(String::from_iter)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A random set of cells in a `cells` by `cells` square, with neither holes nor two cells that
+/// only touch at a corner, so its outline is a single simple loop.
+fn blob(cells: usize, rng: &mut Rng) -> Grid<bool> {
+    let cells = cells.max(1);
+    let mut blob = Grid::new(cells, cells, false);
+
+    let mut frontier = vec![Point::new(cells / 2, cells / 2)];
+    let mut grown = 0;
+    while grown < (cells * cells).div_ceil(2) && !frontier.is_empty() {
+        let cell = frontier.swap_remove(rng.below(frontier.len()));
+        if !blob[cell] {
+            blob[cell] = true;
+            grown += 1;
+            frontier.extend(blob.neighbours4(cell).filter(|&next| !blob[next]));
+        }
+    }
+
+    while fill_holes(&mut blob) | fill_pinches(&mut blob) {}
+    blob
+}
+
+/// Adds every cell that the outside cannot reach. Returns whether anything changed.
+fn fill_holes(blob: &mut Grid<bool>) -> bool {
+    let mut outside = Grid::new(blob.width(), blob.height(), false);
+    let mut queue = blob
+        .positions()
+        .filter(|&cell| blob.is_edge(cell) && !blob[cell])
+        .collect::<Vec<_>>();
+    for &cell in &queue {
+        outside[cell] = true;
+    }
+    while let Some(cell) = queue.pop() {
+        for next in blob.neighbours4(cell).collect::<Vec<_>>() {
+            if !blob[next] && !outside[next] {
+                outside[next] = true;
+                queue.push(next);
+            }
+        }
+    }
+
+    let mut changed = false;
+    for cell in blob.positions().collect::<Vec<_>>() {
+        if !blob[cell] && !outside[cell] {
+            blob[cell] = true;
+            changed = true;
+        }
+    }
+    changed
+}
+
+/// Joins cells that only touch at a corner. Returns whether anything changed.
+fn fill_pinches(blob: &mut Grid<bool>) -> bool {
+    let mut changed = false;
+    for y in 1..blob.height() {
+        for x in 1..blob.width() {
+            let [top_left, top_right, bottom_left, bottom_right] = [
+                Point::new(x - 1, y - 1),
+                Point::new(x, y - 1),
+                Point::new(x - 1, y),
+                Point::new(x, y),
+            ];
+            let diagonal = blob[top_left] && blob[bottom_right];
+            let anti_diagonal = blob[top_right] && blob[bottom_left];
+            if diagonal && !blob[top_right] && !blob[bottom_left] {
+                blob[top_right] = true;
+                changed = true;
+            } else if anti_diagonal && !blob[top_left] && !blob[bottom_right] {
+                blob[top_left] = true;
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+/// The tiles of the blob's outline in walking order, its corners at odd coordinates.
+fn outline(blob: &Grid<bool>) -> Vec<Point> {
+    // Every cell's border, clockwise, keeping the sides it shares with no other cell.
+    let mut next = HashMap::new();
+    for cell in blob.positions().filter(|&cell| blob[cell]) {
+        let (x, y) = (cell.x, cell.y);
+        let inside = |dx: isize, dy: isize| {
+            let (x, y) = (x.checked_add_signed(dx), y.checked_add_signed(dy));
+            x.zip(y)
+                .is_some_and(|(x, y)| blob.get(Point::new(x, y)) == Some(&true))
+        };
+        let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+        for (side, (dx, dy)) in [(0, -1), (1, 0), (0, 1), (-1, 0)].into_iter().enumerate() {
+            if !inside(dx, dy) {
+                next.insert(corners[side], corners[(side + 1) % 4]);
+            }
+        }
+    }
+
+    let first = *next.keys().min().expect("the blob has a cell");
+    let mut corner = first;
+    let mut tiles = Vec::new();
+    loop {
+        let to = next[&corner];
+        tiles.push(Point::new(2 * corner.0 + 1, 2 * corner.1 + 1));
+        tiles.push(Point::new(corner.0 + to.0 + 1, corner.1 + to.1 + 1));
+        corner = to;
+        if corner == first {
+            return tiles;
+        }
+    }
+}
+
+/// The pipe at `at` that connects the tiles `before` and `after`.
+fn pipe(at: Point, before: Point, after: Point) -> char {
+    let side = |other: Point| match (other.x.cmp(&at.x), other.y.cmp(&at.y)) {
+        (_, std::cmp::Ordering::Less) => 'N',
+        (_, std::cmp::Ordering::Greater) => 'S',
+        (std::cmp::Ordering::Less, _) => 'W',
+        _ => 'E',
+    };
+    match (side(before), side(after)) {
+        ('N', 'S') | ('S', 'N') => '|',
+        ('E', 'W') | ('W', 'E') => '-',
+        ('N', 'E') | ('E', 'N') => 'L',
+        ('N', 'W') | ('W', 'N') => 'J',
+        ('S', 'W') | ('W', 'S') => '7',
+        _ => 'F',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{generate, run, Part};
+
+    #[test]
+    fn outlines_a_single_cell() {
+        let blob = Grid::new(1, 1, true);
+        assert_eq!(
+            vec![
+                (1, 1),
+                (2, 1),
+                (3, 1),
+                (3, 2),
+                (3, 3),
+                (2, 3),
+                (1, 3),
+                (1, 2)
+            ],
+            outline(&blob)
+                .into_iter()
+                .map(|tile| (tile.x, tile.y))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn the_loop_encloses_the_blob() -> miette::Result<()> {
+        for seed in 0..10 {
+            let mut rng = Rng::new(seed);
+            let blob = blob(20, &mut rng);
+            let tiles = outline(&blob).len();
+            let cells = blob.iter().filter(|&&cell| cell).count();
+
+            let input = Day10::generate(43, &mut Rng::new(seed));
+            assert_eq!(
+                (tiles / 2).to_string(),
+                run::<Day10>(&input, Part::One)?.value
+            );
+            // Pick's theorem, with every cell of the blob covering four tiles.
+            assert_eq!(
+                (4 * cells - tiles / 2 + 1).to_string(),
+                run::<Day10>(&input, Part::Two)?.value
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generated_input_is_deterministic() {
+        assert_eq!(generate::<Day10>(30, 10), generate::<Day10>(30, 10));
+    }
+}
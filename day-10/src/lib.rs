@@ -1,4 +1,33 @@
+use crate::custom_error::AocError;
+use crate::part1::{parse_input, Maze};
+use aoc_core::Solution;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
 pub mod part1;
 pub mod part2;
+#[cfg(feature = "visual")]
+mod visualize;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Maze;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,20 +1,21 @@
 use crate::custom_error::AocError;
-use itertools::Itertools;
-
-pub type Coordinates = (i32, i32);
+use crate::Day10;
+use aoc_core::{check_chars, Solution};
+use aoc_grid::{Grid, IPoint, Point};
 
 #[derive(Default, Clone, Copy)]
-struct Tile {
-    direction: Direction,
-    gates: (Coordinates, Coordinates),
+pub(crate) struct Tile {
+    pub(crate) pipe: Pipe,
+    gates: (IPoint, IPoint),
     is_edge: bool,
-    is_loop: bool,
-    is_start: bool,
-    position: Coordinates,
+    pub(crate) is_loop: bool,
+    pub(crate) is_start: bool,
+    position: IPoint,
 }
 
+/// The shape of a pipe, named after the two sides it connects.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
-enum Direction {
+pub(crate) enum Pipe {
     #[default]
     NorthEast,
     NorthWest,
@@ -25,9 +26,9 @@ enum Direction {
 }
 
 impl Tile {
-    fn new(gates: (Coordinates, Coordinates), position: Coordinates, direction: Direction) -> Tile {
+    fn new(gates: (IPoint, IPoint), position: IPoint, pipe: Pipe) -> Tile {
         Tile {
-            direction,
+            pipe,
             gates,
             is_edge: false,
             is_loop: false,
@@ -36,10 +37,10 @@ impl Tile {
         }
     }
 
-    fn start_tile(position: Coordinates) -> Tile {
+    fn start_tile(position: IPoint) -> Tile {
         Tile {
-            direction: Direction::default(),
-            gates: ((0, 0), (0, 0)),
+            pipe: Pipe::default(),
+            gates: (IPoint::ORIGIN, IPoint::ORIGIN),
             is_edge: false,
             is_loop: true,
             is_start: true,
@@ -47,7 +48,7 @@ impl Tile {
         }
     }
 
-    fn to(&self, from: Coordinates) -> Coordinates {
+    fn to(&self, from: IPoint) -> IPoint {
         if self.gates.0 == from {
             self.gates.1
         } else {
@@ -56,38 +57,43 @@ impl Tile {
     }
 }
 
-#[derive(Default)]
+#[derive(Clone)]
 pub struct Maze {
     start: Tile,
-    tiles: Vec<Vec<Tile>>,
+    pub(crate) tiles: Grid<Tile>,
 }
 
 impl Maze {
+    /// The tile at `point`, if it lies inside the maze.
+    fn tile_mut(&mut self, point: IPoint) -> Option<&mut Tile> {
+        self.tiles.get_mut(Point::try_from(point).ok()?)
+    }
+
     fn find_start_gates(&mut self) {
         // Define the ranges around the start tile
         let x_range = [
-            (self.start.position.0 - 1).max(0),
-            (self.start.position.0 + 1).min(self.tiles[0].len() as i32 - 1),
+            (self.start.position.x - 1).max(0),
+            (self.start.position.x + 1).min(self.tiles.width() as i64 - 1),
         ];
         let y_range = [
-            (self.start.position.1 - 1).max(0),
-            (self.start.position.1 + 1).min(self.tiles.len() as i32 - 1),
+            (self.start.position.y - 1).max(0),
+            (self.start.position.y + 1).min(self.tiles.height() as i64 - 1),
         ];
 
-        let mut entries: Vec<Coordinates> = Vec::new();
+        let mut entries: Vec<IPoint> = Vec::new();
 
         // Enumerate over every tile in the tiles Vec that is within our defined range
         for i in y_range[0]..=y_range[1] {
             for j in x_range[0]..=x_range[1] {
+                let point = IPoint::new(j, i);
                 // Skip the start tile itself
-                if i == self.start.position.1 && j == self.start.position.0 {
+                if point == self.start.position {
                     continue;
                 }
                 // Check if the tile's list of entries contains the start position
-                if self.tiles[i as usize][j as usize].gates.0 == self.start.position
-                    || self.tiles[i as usize][j as usize].gates.1 == self.start.position
-                {
-                    entries.push((j, i));
+                let gates = self.tiles[Point::new(j as usize, i as usize)].gates;
+                if gates.0 == self.start.position || gates.1 == self.start.position {
+                    entries.push(point);
                 }
             }
         }
@@ -100,51 +106,64 @@ impl Maze {
             self.start.gates.1 = entries[1];
         }
 
-        // Update start tile direction
-        let direction = match (self.start.gates.0, self.start.gates.1) {
-            ((x, y), (x2, y2)) if x == x2 && y < y2 => Direction::SouthNorth,
-            ((x, y), (x2, y2)) if x == x2 && y > y2 => Direction::SouthNorth,
-            ((x, y), (x2, y2)) if x < x2 && y == y2 => Direction::EastWest,
-            ((x, y), (x2, y2)) if (x < x2 && y > y2) && (self.start.position.1 < y) => {
-                Direction::SouthEast
-            }
-            ((x, y), (x2, y2)) if (x < x2 && y < y2) && (self.start.position.0 > x) => {
-                Direction::SouthWest
-            }
-            ((x, y), (x2, y2)) if x < x2 && y > y2 => Direction::NorthWest,
-            ((x, y), (x2, y2)) if x < x2 && y < y2 => Direction::NorthEast,
-            _ => Direction::default(),
+        // Update start tile pipe
+        let (IPoint { x, y }, IPoint { x: x2, y: y2 }) = self.start.gates;
+        let pipe = if x == x2 && y != y2 {
+            Pipe::SouthNorth
+        } else if x < x2 && y == y2 {
+            Pipe::EastWest
+        } else if (x < x2 && y > y2) && (self.start.position.y < y) {
+            Pipe::SouthEast
+        } else if (x < x2 && y < y2) && (self.start.position.x > x) {
+            Pipe::SouthWest
+        } else if x < x2 && y > y2 {
+            Pipe::NorthWest
+        } else if x < x2 && y < y2 {
+            Pipe::NorthEast
+        } else {
+            Pipe::default()
         };
-        self.start.direction = direction;
-        self.tiles[self.start.position.1 as usize][self.start.position.0 as usize].direction =
-            direction;
+        self.start.pipe = pipe;
+        let start = self.start.position;
+        if let Some(tile) = self.tile_mut(start) {
+            tile.pipe = pipe;
+        }
     }
 
-    pub(crate) fn walk_loop(&mut self) -> Vec<Coordinates> {
+    /// Follows the pipes from the start until they lead back to it, failing at the first one
+    /// that leads out of the maze or into a tile that does not connect back.
+    pub(crate) fn walk_loop(&mut self) -> Result<Vec<IPoint>, AocError> {
         let mut loop_tiles = Vec::new();
         let mut next = self.start.gates.0;
         loop_tiles.push(next);
         let mut prev = self.start.position;
 
         while next != self.start.position {
-            let tile = &mut self.tiles[next.1 as usize][next.0 as usize];
+            let broken = || AocError::BrokenLoop {
+                x: next.x,
+                y: next.y,
+            };
+            let tile = self.tile_mut(next).ok_or_else(broken)?;
+            if tile.gates.0 != prev && tile.gates.1 != prev {
+                return Err(broken());
+            }
             tile.is_loop = true;
             let cur = next;
             next = tile.to(prev);
             prev = cur;
             loop_tiles.push(next);
         }
-        loop_tiles
+        Ok(loop_tiles)
     }
 
-    fn ray_cast_tile(&self, from: Coordinates) -> i32 {
+    fn ray_cast_tile(&self, from: Point) -> i32 {
         let mut count = 0;
-        for i in 0..from.0 {
-            let tile = &self.tiles[from.1 as usize][i as usize];
+        for x in 0..from.x {
+            let tile = &self.tiles[Point::new(x, from.y)];
             if tile.is_loop
-                && (tile.direction == Direction::SouthNorth
-                    || tile.direction == Direction::SouthWest
-                    || tile.direction == Direction::SouthEast)
+                && (tile.pipe == Pipe::SouthNorth
+                    || tile.pipe == Pipe::SouthWest
+                    || tile.pipe == Pipe::SouthEast)
             {
                 count += 1;
             }
@@ -156,84 +175,92 @@ impl Maze {
         }
     }
 
+    /// Whether the tile at `point` lies inside the loop, once the loop has been walked.
+    pub(crate) fn is_enclosed(&self, point: Point) -> bool {
+        let tile = &self.tiles[point];
+        !tile.is_loop && !tile.is_edge && self.ray_cast_tile(point) == 1
+    }
+
     pub(crate) fn count_enclosed(&self) -> i32 {
-        let mut count = 0;
-        for (y, row) in self.tiles.iter().enumerate() {
-            for (x, tile) in row.iter().enumerate() {
-                if !tile.is_loop && !tile.is_edge {
-                    count += self.ray_cast_tile((x as i32, y as i32));
-                }
-            }
-        }
-        count
+        self.tiles
+            .positions()
+            .filter(|&point| self.is_enclosed(point))
+            .count() as i32
     }
 }
 
-pub fn parse_input(input: &[String]) -> Maze {
-    let mut tiles: Vec<Vec<Tile>> = Vec::new();
-    let mut maze = Maze::default();
-    for (y, line) in input.iter().enumerate() {
-        let mut row: Vec<Tile> = Vec::new();
-        for (x, c) in line.chars().enumerate() {
-            let mut tile = match c {
-                '-' => Tile::new(
-                    ((x as i32 - 1, y as i32), (x as i32 + 1, y as i32)),
-                    (x as i32, y as i32),
-                    Direction::EastWest,
-                ),
-                '.' => Tile::new(
-                    Default::default(),
-                    (x as i32, y as i32),
-                    Direction::default(),
-                ),
-                '7' => Tile::new(
-                    ((x as i32, y as i32 + 1), (x as i32 - 1, y as i32)),
-                    (x as i32, y as i32),
-                    Direction::SouthWest,
-                ),
-                'F' => Tile::new(
-                    ((x as i32, y as i32 + 1), (x as i32 + 1, y as i32)),
-                    (x as i32, y as i32),
-                    Direction::SouthEast,
-                ),
-                'J' => Tile::new(
-                    ((x as i32, y as i32 - 1), (x as i32 - 1, y as i32)),
-                    (x as i32, y as i32),
-                    Direction::NorthWest,
-                ),
-                'L' => Tile::new(
-                    ((x as i32, y as i32 - 1), (x as i32 + 1, y as i32)),
-                    (x as i32, y as i32),
-                    Direction::NorthEast,
-                ),
-                'S' => Tile::start_tile((x as i32, y as i32)),
-                '|' => Tile::new(
-                    ((x as i32, y as i32 - 1), (x as i32, y as i32 + 1)),
-                    (x as i32, y as i32),
-                    Direction::SouthNorth,
-                ),
-                _ => panic!("Unknown tile type: {}", c),
-            };
-            if tile.is_start {
-                maze.start = tile;
-            }
-            if x == 0 || x == line.len() - 1 || y == 0 || y == input.len() - 1 {
-                tile.is_edge = true;
-            }
-            row.push(tile);
-        }
-        tiles.push(row);
-    }
-    maze.tiles = tiles;
+const TILES: &str = "|-LJ7F.S";
+
+pub fn parse_input(input: &str) -> Result<Maze, AocError> {
+    check_chars(
+        input,
+        input,
+        |c| TILES.contains(c) || c == '\n',
+        "unknown tile",
+    )
+    .map_err(|err| err.with_label(format!("expected one of {TILES}")))?;
+    let grid = Grid::<char>::parse(input)?;
+    let tiles = grid.map_indexed(|point, &c| {
+        let at = IPoint::from(point);
+        let (x, y) = (at.x, at.y);
+        let mut tile = match c {
+            '-' => Tile::new(
+                (IPoint::new(x - 1, y), IPoint::new(x + 1, y)),
+                at,
+                Pipe::EastWest,
+            ),
+            '.' => Tile::new(Default::default(), at, Pipe::default()),
+            '7' => Tile::new(
+                (IPoint::new(x, y + 1), IPoint::new(x - 1, y)),
+                at,
+                Pipe::SouthWest,
+            ),
+            'F' => Tile::new(
+                (IPoint::new(x, y + 1), IPoint::new(x + 1, y)),
+                at,
+                Pipe::SouthEast,
+            ),
+            'J' => Tile::new(
+                (IPoint::new(x, y - 1), IPoint::new(x - 1, y)),
+                at,
+                Pipe::NorthWest,
+            ),
+            'L' => Tile::new(
+                (IPoint::new(x, y - 1), IPoint::new(x + 1, y)),
+                at,
+                Pipe::NorthEast,
+            ),
+            'S' => Tile::start_tile(at),
+            '|' => Tile::new(
+                (IPoint::new(x, y - 1), IPoint::new(x, y + 1)),
+                at,
+                Pipe::SouthNorth,
+            ),
+            _ => unreachable!("tiles are checked while parsing"),
+        };
+        tile.is_edge = grid.is_edge(point);
+        tile
+    });
+
+    let start = tiles
+        .iter()
+        .find(|tile| tile.is_start)
+        .copied()
+        .unwrap_or_default();
+    let mut maze = Maze { start, tiles };
     maze.find_start_gates();
-    maze
+    Ok(maze)
 }
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<i32, AocError> {
-    let lines = input.lines().map(|s| s.to_string()).collect_vec();
-    let mut maze = parse_input(&lines);
-    let walk = maze.walk_loop();
+    solve(&Day10::parse(input)?)
+}
+
+#[tracing::instrument(skip(maze))]
+pub fn solve(maze: &Maze) -> Result<i32, AocError> {
+    let mut maze = maze.clone();
+    let walk = maze.walk_loop()?;
     Ok(walk.len() as i32 / 2)
 }
 
@@ -251,4 +278,10 @@ mod tests {
         assert_eq!(4, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn broken_loops_are_rejected() {
+        assert!(process("S-7\n|.|\nL-.").is_err());
+        assert!(process("S-7\n|.|\nL--").is_err());
+    }
 }
@@ -1,12 +1,17 @@
 use crate::custom_error::AocError;
-use crate::part1::parse_input;
-use itertools::Itertools;
+use crate::part1::Maze;
+use crate::Day10;
+use aoc_core::Solution;
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<i32, AocError> {
-    let lines = input.lines().map(|s| s.to_string()).collect_vec();
-    let mut maze = parse_input(&lines);
-    maze.walk_loop();
+    solve(&Day10::parse(input)?)
+}
+
+#[tracing::instrument(skip(maze))]
+pub fn solve(maze: &Maze) -> Result<i32, AocError> {
+    let mut maze = maze.clone();
+    maze.walk_loop()?;
     Ok(maze.count_enclosed())
 }
 
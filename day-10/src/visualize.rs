@@ -0,0 +1,120 @@
+use crate::part1::{Pipe, Tile};
+use crate::Day10;
+use aoc_core::Part;
+use aoc_grid::IPoint;
+use aoc_visual::{Cell, Color, Scene, Visualize};
+
+/// The loop in box-drawing characters over the other tiles, dimmed. Part 1 marks the tile
+/// farthest from the start, part 2 shades the tiles the loop encloses.
+impl Visualize for Day10 {
+    fn visualize(
+        maze: &Self::Input,
+        part: Part,
+        show: &mut dyn FnMut(Scene),
+    ) -> Result<(), Self::Error> {
+        let mut maze = maze.clone();
+        let walk = maze.walk_loop()?;
+
+        let scene = match part {
+            Part::One => {
+                let farthest = walk[(walk.len() / 2).saturating_sub(1)];
+                let cells = maze.tiles.map_indexed(|point, tile| {
+                    let cell = draw(tile);
+                    match IPoint::from(point) == farthest {
+                        true => cell.fg(Color::DIM).bg(Color::ACCENT),
+                        false => cell,
+                    }
+                });
+                Scene::new(
+                    format!("farthest from the start: {} steps", walk.len() / 2),
+                    cells,
+                )
+            }
+            Part::Two => {
+                let cells = maze
+                    .tiles
+                    .map_indexed(|point, tile| match maze.is_enclosed(point) {
+                        true => Cell::new('•').fg(Color::ACCENT).bg(Color::FILL),
+                        false => draw(tile),
+                    });
+                Scene::new(format!("enclosed: {} tiles", maze.count_enclosed()), cells)
+            }
+        };
+        show(scene);
+        Ok(())
+    }
+}
+
+fn draw(tile: &Tile) -> Cell {
+    if !tile.is_loop {
+        return Cell::new('·').fg(Color::DIM);
+    }
+
+    let glyph = match tile.pipe {
+        Pipe::NorthEast => '└',
+        Pipe::NorthWest => '┘',
+        Pipe::SouthEast => '┌',
+        Pipe::SouthWest => '┐',
+        Pipe::SouthNorth => '│',
+        Pipe::EastWest => '─',
+    };
+    match tile.is_start {
+        true => Cell::new(glyph).fg(Color::FILL).bg(Color::ACCENT),
+        false => Cell::new(glyph).fg(Color::ACCENT),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use aoc_visual::to_ansi;
+
+    fn frames(input: &str, part: Part) -> miette::Result<Vec<String>> {
+        let maze = Day10::parse(input)?;
+        let mut frames = Vec::new();
+        Day10::visualize(&maze, part, &mut |scene| {
+            frames.push(to_ansi(&scene, false))
+        })?;
+        Ok(frames)
+    }
+
+    #[test]
+    fn draws_the_loop_with_box_characters() -> miette::Result<()> {
+        let input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        assert_eq!(
+            vec![
+                "enclosed: 4 tiles
+··········
+·┌──────┐·
+·│┌────┐│·
+·││····││·
+·││····││·
+·│└─┐┌─┘│·
+·│••││••│·
+·└──┘└──┘·
+··········
+"
+            ],
+            frames(input, Part::Two)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn marks_the_farthest_tile() -> miette::Result<()> {
+        let frames = frames(".....\n.S-7.\n.|.|.\n.L-J.\n.....", Part::One)?;
+        assert_eq!(1, frames.len());
+        assert!(frames[0].starts_with("farthest from the start: 4 steps\n"));
+        assert!(frames[0].contains("·┌─┐·\n·│·│·\n·└─┘·"));
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use crate::part1::Universe;
+use crate::shared::Image;
+use crate::{part1, part2, Day11};
+use aoc_core::Part;
+use aoc_visual::{Color, Drawing, Export, Shape};
+
+/// Space between the two pictures of the universe, in tiles.
+const GAP: f64 = 2.0;
+
+/// The galaxies before the universe expands, with the empty rows and columns shaded, and next
+/// to them the galaxies after it has. The expanded universe is shrunk to the same height, which
+/// for part 2 packs a million rows into each shaded band.
+impl Export for Day11 {
+    fn export(image: &Self::Input, part: Part) -> Result<Drawing, Self::Error> {
+        let rate = match part {
+            Part::One => part1::EXPANSION_RATE,
+            Part::Two => part2::EXPANSION_RATE,
+        };
+        let universe = Universe::new(image.galaxies.clone(), image.height, image.width, rate);
+        let (expanded_width, expanded_height) = universe.size();
+
+        let (width, height) = (image.width as f64, image.height as f64);
+        let scale = match expanded_height {
+            0 => 1.0,
+            expanded => height / expanded as f64,
+        };
+        let left = width + GAP;
+
+        let mut drawing = Drawing::new(
+            format!(
+                "galaxies before and after expanding, {} apart in total",
+                universe.calculate_distances()
+            ),
+            left + expanded_width as f64 * scale,
+            height,
+        );
+
+        let (columns, rows) = empty(image);
+        for (k, &x) in columns.iter().enumerate() {
+            let expanded = (x + k * rate) as f64;
+            drawing.push(band(x as f64, 0.0, 1.0, height));
+            drawing.push(band(
+                left + expanded * scale,
+                0.0,
+                (rate + 1) as f64 * scale,
+                height,
+            ));
+        }
+        for (k, &y) in rows.iter().enumerate() {
+            let expanded = (y + k * rate) as f64;
+            drawing.push(band(0.0, y as f64, width, 1.0));
+            drawing.push(band(
+                left,
+                expanded * scale,
+                expanded_width as f64 * scale,
+                (rate + 1) as f64 * scale,
+            ));
+        }
+
+        for galaxy in &image.galaxies {
+            drawing.push(galaxy_at(galaxy.x as f64 + 0.5, galaxy.y as f64 + 0.5));
+        }
+        for galaxy in universe.galaxies() {
+            drawing.push(galaxy_at(
+                left + (galaxy.x as f64 + 0.5) * scale,
+                (galaxy.y as f64 + 0.5) * scale,
+            ));
+        }
+        Ok(drawing)
+    }
+}
+
+/// The columns and the rows of `image` without a galaxy, in order.
+fn empty(image: &Image) -> (Vec<usize>, Vec<usize>) {
+    let columns = (0..image.width)
+        .filter(|&x| image.galaxies.iter().all(|galaxy| galaxy.x != x))
+        .collect();
+    let rows = (0..image.height)
+        .filter(|&y| image.galaxies.iter().all(|galaxy| galaxy.y != y))
+        .collect();
+    (columns, rows)
+}
+
+fn band(x: f64, y: f64, width: f64, height: f64) -> Shape {
+    Shape::Rect {
+        x,
+        y,
+        width,
+        height,
+        fill: Color::FILL,
+    }
+}
+
+/// A galaxy stays the same size in both pictures, however far the expanded one is shrunk.
+fn galaxy_at(x: f64, y: f64) -> Shape {
+    Shape::Circle {
+        x,
+        y,
+        radius: 0.4,
+        fill: Color::ACCENT,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+
+    fn circles(drawing: &Drawing) -> Vec<(f64, f64)> {
+        drawing
+            .shapes
+            .iter()
+            .filter_map(|shape| match *shape {
+                Shape::Circle { x, y, .. } => Some((x, y)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn draws_galaxies_before_and_after_expanding() -> miette::Result<()> {
+        // One empty column and one empty row between two galaxies.
+        let image = Day11::parse("#..\n...\n..#")?;
+        let drawing = Day11::export(&image, Part::One)?;
+
+        assert_eq!(
+            "galaxies before and after expanding, 6 apart in total",
+            drawing.title
+        );
+        // The expanded universe is 4 by 4, shrunk to 3 tiles high.
+        assert_eq!((3.0 + GAP + 3.0, 3.0), (drawing.width, drawing.height));
+        assert_eq!(
+            vec![
+                (0.5, 0.5),
+                (2.5, 2.5),
+                (5.0 + 0.5 * 0.75, 0.5 * 0.75),
+                (5.0 + 3.5 * 0.75, 3.5 * 0.75)
+            ],
+            circles(&drawing)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn shrinks_a_million_fold_expansion_to_the_same_height() -> miette::Result<()> {
+        let image = Day11::parse("#..\n...\n..#")?;
+        let drawing = Day11::export(&image, Part::Two)?;
+
+        assert_eq!(3.0, drawing.height);
+        let after = circles(&drawing)[3];
+        assert!((after.1 - 3.0).abs() < 1e-5, "{after:?}");
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Day11;
+use aoc_core::{Generator, Rng};
+
+/// A `size` by `size` image with sparse galaxies and about one empty row and column in ten,
+/// which the universe expands.
+impl Generator for Day11 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(2);
+        let mut empty = |_| rng.chance(0.1);
+        let empty_rows = (0..size).map(&mut empty).collect::<Vec<_>>();
+        let empty_columns = (0..size).map(&mut empty).collect::<Vec<_>>();
+
+        (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        let galaxy = !empty_rows[y] && !empty_columns[x] && rng.chance(0.03);
+                        if galaxy {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate;
+
+    #[test]
+    fn generates_galaxies() {
+        assert!(generate::<Day11>(140, 11).contains('#'));
+    }
+}
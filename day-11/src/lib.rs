@@ -1,5 +1,34 @@
+use crate::custom_error::AocError;
+use crate::shared::Image;
+use aoc_core::Solution;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+#[cfg(feature = "visual")]
+mod export;
+mod generator;
 pub mod part1;
 pub mod part2;
 mod shared;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Image;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Image::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
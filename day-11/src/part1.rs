@@ -1,29 +1,23 @@
 use crate::custom_error::AocError;
-use crate::shared::parse_input;
+use crate::shared::Image;
+use crate::Day11;
+use aoc_core::Solution;
+use aoc_grid::Point;
 use itertools::Itertools;
 
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
-pub struct Galaxy {
-    x: usize,
-    y: usize,
-}
-
-impl Galaxy {
-    pub(crate) fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-}
+/// Each empty row or column becomes two of them.
+pub(crate) const EXPANSION_RATE: usize = 1;
 
 pub struct Universe {
     height: usize,
     width: usize,
     expansion_rate: usize,
-    galaxies: Vec<Galaxy>,
+    galaxies: Vec<Point>,
 }
 
 impl Universe {
     pub(crate) fn new(
-        galaxies: Vec<Galaxy>,
+        galaxies: Vec<Point>,
         height: usize,
         width: usize,
         expansion_rate: usize,
@@ -42,17 +36,24 @@ impl Universe {
         let mut sum: usize = 0;
         for i in 0..self.galaxies.len() {
             for j in i + 1..self.galaxies.len() {
-                sum += Self::distance(
-                    self.galaxies[i].x as i64,
-                    self.galaxies[i].y as i64,
-                    self.galaxies[j].x as i64,
-                    self.galaxies[j].y as i64,
-                );
+                sum += self.galaxies[i].manhattan(self.galaxies[j]);
             }
         }
         sum
     }
 
+    /// Where the galaxies are once the universe has expanded.
+    #[cfg(feature = "visual")]
+    pub(crate) fn galaxies(&self) -> &[Point] {
+        &self.galaxies
+    }
+
+    /// The width and height of the expanded universe.
+    #[cfg(feature = "visual")]
+    pub(crate) fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
     fn expand(&mut self) {
         let x_coords = self.galaxies.iter().map(|c| c.x).collect_vec();
         let y_coords = self.galaxies.iter().map(|c| c.y).collect_vec();
@@ -84,18 +85,24 @@ impl Universe {
             coord.x += x_gt(coord.x as u64) * self.expansion_rate;
             coord.y += y_gt(coord.y as u64) * self.expansion_rate;
         });
-    }
-
-    fn distance(x1: i64, y1: i64, x2: i64, y2: i64) -> usize {
-        ((x2 - x1).abs() + (y2 - y1).abs()) as usize
+        self.width += x_expanded.len() * self.expansion_rate;
+        self.height += y_expanded.len() * self.expansion_rate;
     }
 }
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-    let universe = Universe::new(parse_input(input), height, width, 1);
+    solve(&Day11::parse(input)?)
+}
+
+#[tracing::instrument(skip(image))]
+pub fn solve(image: &Image) -> Result<usize, AocError> {
+    let universe = Universe::new(
+        image.galaxies.clone(),
+        image.height,
+        image.width,
+        EXPANSION_RATE,
+    );
     Ok(universe.calculate_distances())
 }
 
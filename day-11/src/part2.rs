@@ -1,12 +1,25 @@
 use crate::custom_error::AocError;
 use crate::part1::Universe;
-use crate::shared::parse_input;
+use crate::shared::Image;
+use crate::Day11;
+use aoc_core::Solution;
+
+/// Each empty row or column becomes a million of them.
+pub(crate) const EXPANSION_RATE: usize = 999_999;
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let width = input.lines().next().unwrap().len();
-    let height = input.lines().count();
-    let universe = Universe::new(parse_input(input), height, width, 999_999);
+    solve(&Day11::parse(input)?)
+}
+
+#[tracing::instrument(skip(image))]
+pub fn solve(image: &Image) -> Result<usize, AocError> {
+    let universe = Universe::new(
+        image.galaxies.clone(),
+        image.height,
+        image.width,
+        EXPANSION_RATE,
+    );
     Ok(universe.calculate_distances())
 }
 
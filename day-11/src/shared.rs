@@ -1,17 +1,28 @@
-use crate::part1::Galaxy;
+use aoc_grid::{Grid, GridError, Point};
 use itertools::Itertools;
 
-pub fn parse_input(input: &str) -> Vec<Galaxy> {
-    let galaxies = input
-        .lines()
-        .map(|line| line.to_string())
-        .enumerate()
-        .flat_map(|(i, line)| {
-            line.char_indices()
-                .filter(|(_, c)| *c == '#')
-                .map(move |(j, _)| Galaxy::new(i, j))
-                .collect_vec()
+/// The telescope image before any cosmic expansion is applied.
+pub struct Image {
+    pub galaxies: Vec<Point>,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl Image {
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        let grid = Grid::<char>::parse(input)?;
+
+        Ok(Self {
+            galaxies: find_galaxies(&grid),
+            height: grid.height(),
+            width: grid.width(),
         })
-        .collect_vec();
-    galaxies
+    }
+}
+
+pub fn find_galaxies(grid: &Grid<char>) -> Vec<Point> {
+    grid.enumerate()
+        .filter(|(_, c)| **c == '#')
+        .map(|(point, _)| point)
+        .collect_vec()
 }
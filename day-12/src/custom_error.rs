@@ -6,4 +6,10 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseError(#[from] aoc_core::ParseError),
+    #[error("there are too many arrangements to count")]
+    #[diagnostic(code(aoc::overflow))]
+    Overflow,
 }
@@ -0,0 +1,33 @@
+use crate::Day12;
+use aoc_core::{Generator, Rng};
+
+/// `size` condition records of up to 20 springs, each with at least one arrangement: a row
+/// of known springs is made up first, then some of them are blotted out with `?`.
+impl Generator for Day12 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let length = rng.range(1..=20);
+                let mut known = (0..length)
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect::<Vec<_>>();
+                if !known.contains(&'#') {
+                    known[rng.below(length)] = '#';
+                }
+
+                let groups = String::from_iter(&known)
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(|group| group.len().to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let springs = known
+                    .into_iter()
+                    .map(|spring| if rng.chance(0.5) { '?' } else { spring })
+                    .collect::<String>();
+                format!("{springs} {groups}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
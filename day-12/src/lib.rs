@@ -1,4 +1,37 @@
+use crate::custom_error::AocError;
+use crate::part1::Record;
+use aoc_core::{ParseError, Solution};
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
 pub mod part1;
 pub mod part2;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Record>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.parse::<Record>()
+                    .map_err(|err| ParseError::at(input, line, err))
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,31 +1,30 @@
 use derive_more::Into;
 use std::fmt::Debug;
+use std::iter::repeat_n;
 use std::ops::Range;
 use std::str::FromStr;
 
+use aoc_core::par::prelude::*;
 use itertools::Itertools;
-use rayon::iter::IntoParallelRefIterator;
-use rayon::iter::ParallelIterator;
 
 use crate::custom_error::AocError;
+use crate::Day12;
+use aoc_core::Solution;
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    compute(input)
+    solve(&Day12::parse(input)?)
 }
 
-pub fn compute(input: &str) -> Result<usize, AocError> {
-    let records = input
-        .lines()
-        .map(|line| line.parse::<Record>().unwrap())
-        .collect_vec();
-
-    let result = records
+#[tracing::instrument(skip(records))]
+pub fn solve(records: &[Record]) -> Result<usize, AocError> {
+    records
         .par_iter()
         .map(|r| possible_arrangements(r.springs.clone(), r.groups.clone()))
-        .sum();
-
-    Ok(result)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .try_fold(0usize, |sum, count| sum.checked_add(count?))
+        .ok_or(AocError::Overflow)
 }
 
 const DAMAGED: &str = "#";
@@ -35,11 +34,21 @@ pub const UNKNOWN: &str = "?";
 const UNKNOWN_CHAR: char = '?';
 
 #[derive(Debug, Into)]
-struct Record {
+pub struct Record {
     pub springs: String,
     pub groups: Vec<usize>,
 }
 
+impl Record {
+    /// The unfolded record: springs repeated five times joined by unknowns, groups five times.
+    pub fn unfold(&self) -> Self {
+        Self {
+            springs: repeat_n(self.springs.as_str(), 5).join(UNKNOWN),
+            groups: self.groups.repeat(5),
+        }
+    }
+}
+
 impl FromStr for Record {
     type Err = &'static str;
 
@@ -50,6 +59,10 @@ impl FromStr for Record {
             Err(_) => return Err("Invalid record format."),
         };
 
+        if !records.chars().all(|c| "#.?".contains(c)) {
+            return Err("Invalid spring condition.");
+        }
+
         let springs = records.chars().collect();
         let info = info
             .split(',')
@@ -57,9 +70,15 @@ impl FromStr for Record {
                 value
                     .parse::<usize>()
                     .map_err(|_| "Failed to parse info value.")
-                    .unwrap()
             })
-            .collect_vec();
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if info
+            .iter()
+            .any(|&group| group == 0 || group > records.len())
+        {
+            return Err("Group size out of range.");
+        }
 
         Ok(Self {
             springs,
@@ -68,7 +87,8 @@ impl FromStr for Record {
     }
 }
 
-pub(crate) fn possible_arrangements(springs: String, groups: Vec<usize>) -> usize {
+/// The number of ways to fill in the unknown springs, or `None` if there are too many to count.
+pub(crate) fn possible_arrangements(springs: String, groups: Vec<usize>) -> Option<usize> {
     let mut dp = init_dp(&springs, &groups);
     let mut min_j = 0;
     'main: for i in 0..springs.len() {
@@ -91,10 +111,10 @@ pub(crate) fn possible_arrangements(springs: String, groups: Vec<usize>) -> usiz
             if is_valid(&springs, &groups, &dp, i, j) {
                 continue;
             }
-            update_dp(&springs, &groups, &mut dp, i, j);
+            update_dp(&springs, &groups, &mut dp, i, j)?;
         }
     }
-    extract_result(&dp)
+    Some(extract_result(&dp))
 }
 
 fn init_dp(springs: &str, groups: &[usize]) -> Vec<Vec<usize>> {
@@ -105,13 +125,21 @@ fn extract_result(dp: &[Vec<usize>]) -> usize {
     dp[dp.len() - 1][dp[dp.len() - 1].len() - 1]
 }
 
-fn update_dp(springs: &str, groups: &[usize], dp: &mut [Vec<usize>], i: usize, j: usize) {
+fn update_dp(
+    springs: &str,
+    groups: &[usize],
+    dp: &mut [Vec<usize>],
+    i: usize,
+    j: usize,
+) -> Option<()> {
     for k in next_indices(springs, groups, dp, i, j) {
-        match j > 0 {
-            true => dp[k][j] += dp[i][j - 1],
-            false => dp[k][j] += 1,
-        }
+        let ways = match j > 0 {
+            true => dp[i][j - 1],
+            false => 1,
+        };
+        dp[k][j] = dp[k][j].checked_add(ways)?;
     }
+    Some(())
 }
 
 fn is_valid(springs: &str, groups: &[usize], dp: &[Vec<usize>], i: usize, j: usize) -> bool {
@@ -168,10 +196,81 @@ fn can_be_placed(dp: &[Vec<usize>], i: usize, j: usize) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
     use rstest::rstest;
 
     use super::*;
 
+    /// Tries every assignment of the unknown springs, which is only feasible for short rows.
+    fn brute_force(springs: &str, groups: &[usize]) -> usize {
+        let unknown = springs.chars().filter(|&c| c == '?').count();
+        (0..1usize << unknown)
+            .filter(|assignment| {
+                let mut bit = 0;
+                let row = springs
+                    .chars()
+                    .map(|c| match c {
+                        '?' => {
+                            bit += 1;
+                            if assignment >> (bit - 1) & 1 == 1 {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        }
+                        c => c,
+                    })
+                    .collect::<String>();
+                let damaged = row
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(str::len)
+                    .collect::<Vec<_>>();
+                damaged == groups
+            })
+            .count()
+    }
+
+    /// A row with at least one arrangement: a known row with some springs hidden again.
+    fn solvable_record() -> impl Strategy<Value = (String, Vec<usize>)> {
+        prop::collection::vec((any::<bool>(), any::<bool>()), 1..=14)
+            .prop_filter("needs a damaged spring", |row| {
+                row.iter().any(|&(damaged, _)| damaged)
+            })
+            .prop_map(|row| {
+                let known = row
+                    .iter()
+                    .map(|&(damaged, _)| if damaged { '#' } else { '.' })
+                    .collect::<String>();
+                let groups = known
+                    .split('.')
+                    .filter(|group| !group.is_empty())
+                    .map(str::len)
+                    .collect();
+                let springs = row
+                    .iter()
+                    .zip(known.chars())
+                    .map(|(&(_, hidden), c)| if hidden { '?' } else { c })
+                    .collect();
+                (springs, groups)
+            })
+    }
+
+    /// Any row with any groups, most of which have no arrangement at all.
+    fn arbitrary_record() -> impl Strategy<Value = (String, Vec<usize>)> {
+        ("[#.?]{1,14}", prop::collection::vec(1..=4usize, 1..=4))
+    }
+
+    proptest! {
+        #[test]
+        fn counting_agrees_with_brute_force(
+            (springs, groups) in prop_oneof![solvable_record(), arbitrary_record()]
+        ) {
+            let expected = brute_force(&springs, &groups);
+            prop_assert_eq!(Some(expected), possible_arrangements(springs, groups));
+        }
+    }
+
     #[rstest]
     #[case("???.### 1,1,3", 1)]
     #[case(".??..??...?##. 1,1,3", 4)]
@@ -182,11 +281,19 @@ mod tests {
     fn test_permutations(#[case] input: &str, #[case] expected: usize) {
         let record = input.parse::<Record>().unwrap();
         assert_eq!(
-            expected,
+            Some(expected),
             possible_arrangements(record.springs, record.groups)
         )
     }
 
+    #[rstest]
+    #[case("???.### 1,1,0")]
+    #[case("???.### 1,1,8")]
+    #[case("???.### 1,1,18446744073709551615")]
+    fn group_sizes_are_checked(#[case] input: &str) {
+        assert!(input.parse::<Record>().is_err());
+    }
+
     #[test]
     fn base_test() -> miette::Result<()> {
         let input = "???.### 1,1,3
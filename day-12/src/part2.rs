@@ -1,69 +1,32 @@
-use std::iter::repeat;
-use std::str::FromStr;
-
 use crate::custom_error::AocError;
-use crate::part1::{possible_arrangements, UNKNOWN};
-use itertools::Itertools;
-use rayon::iter::IntoParallelRefIterator;
-use rayon::iter::ParallelIterator;
-
-#[derive(Debug)]
-struct Record {
-    springs: String,
-    groups: Vec<usize>,
-}
-
-impl FromStr for Record {
-    type Err = &'static str;
-
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let result = value.split_once(' ').ok_or(());
-        let (springs, groups) = match result {
-            Ok(result) => result,
-            Err(_) => return Err("Invalid record format."),
-        };
+use crate::part1::{possible_arrangements, Record};
+use crate::Day12;
+use aoc_core::par::prelude::*;
+use aoc_core::Solution;
 
-        let springs = repeat(springs).take(5).collect_vec().join(UNKNOWN);
-        let groups = groups
-            .split(',')
-            .map(|value| {
-                value
-                    .parse::<usize>()
-                    .map_err(|_| "Failed to parse info value.")
-                    .unwrap()
-            })
-            .collect_vec()
-            .repeat(5);
-
-        Ok(Self { springs, groups })
-    }
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    solve(&Day12::parse(input)?)
 }
 
-pub fn compute(input: &str) -> Result<usize, AocError> {
-    let records = input
-        .lines()
-        .map(|line| line.parse::<Record>().unwrap())
-        .collect_vec();
-
-    let result = records
+#[tracing::instrument(skip(records))]
+pub fn solve(records: &[Record]) -> Result<usize, AocError> {
+    records
         .par_iter()
-        .map(|r| possible_arrangements(r.springs.clone(), r.groups.clone()))
-        .sum();
-
-    Ok(result)
-}
-
-#[tracing::instrument(skip(input))]
-pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    compute(input)
+        .map(Record::unfold)
+        .map(|r| possible_arrangements(r.springs, r.groups))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .try_fold(0usize, |sum, count| sum.checked_add(count?))
+        .ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
 
-    use crate::part1::possible_arrangements;
-    use crate::part2::{process, Record};
+    use crate::part1::{possible_arrangements, Record};
+    use crate::part2::process;
 
     #[rstest]
     #[case("???.### 1,1,3", 1)]
@@ -73,9 +36,9 @@ mod tests {
     #[case("????.######..#####. 1,6,5", 2500)]
     #[case("?###???????? 3,2,1", 506250)]
     fn test_permutations(#[case] input: &str, #[case] expected: usize) {
-        let record = input.parse::<Record>().unwrap();
+        let record = input.parse::<Record>().unwrap().unfold();
         assert_eq!(
-            expected,
+            Some(expected),
             possible_arrangements(record.springs, record.groups)
         )
     }
@@ -91,4 +54,10 @@ mod tests {
         assert_eq!(525152, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn too_many_arrangements_are_reported() {
+        let record = format!("{} {}", "?".repeat(99), vec!["1"; 20].join(","));
+        assert!(process(&record).is_err());
+    }
 }
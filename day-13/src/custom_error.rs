@@ -6,4 +6,10 @@ pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    GridError(#[from] aoc_grid::GridError),
+    #[error("pattern {0} does not have exactly one line of reflection")]
+    #[diagnostic(code(aoc::invalid_input))]
+    NoReflection(usize),
 }
@@ -0,0 +1,178 @@
+use crate::Day13;
+use aoc_core::{Generator, Rng};
+
+/// A line of reflection: after this many rows, or this many columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    Row(usize),
+    Column(usize),
+}
+
+impl Line {
+    fn random(height: usize, width: usize, rng: &mut Rng) -> Self {
+        match rng.chance(0.5) {
+            true => Line::Row(rng.range(1..=height - 1)),
+            false => Line::Column(rng.range(1..=width - 1)),
+        }
+    }
+
+    /// Where `(x, y)` ends up when mirrored across the line, if that is still in the pattern.
+    fn mirror(self, (x, y): (usize, usize), height: usize, width: usize) -> Option<(usize, usize)> {
+        let across = |at: usize, line: usize, len: usize| {
+            (2 * line).checked_sub(at + 1).filter(|&to| to < len)
+        };
+        match self {
+            Line::Row(row) => across(y, row, height).map(|y| (x, y)),
+            Line::Column(column) => across(x, column, width).map(|x| (x, y)),
+        }
+    }
+}
+
+type Pattern = Vec<Vec<bool>>;
+
+/// `size` patterns of ash and rocks with exactly one line of reflection each, and exactly one
+/// other line that is a reflection once a single smudge is cleaned off.
+impl Generator for Day13 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| render(&pattern(rng).0))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// A pattern with its clean and its smudged line of reflection.
+///
+/// The pattern starts out symmetric across both lines; then one cell that only the smudged
+/// line reflects is flipped. Patterns that happen to reflect across other lines as well are
+/// thrown away.
+fn pattern(rng: &mut Rng) -> (Pattern, Line, Line) {
+    loop {
+        let (height, width) = (rng.range(5..=17), rng.range(5..=17));
+        let clean = Line::random(height, width, rng);
+        let smudged = Line::random(height, width, rng);
+        if clean == smudged {
+            continue;
+        }
+
+        // Cells that mirror onto each other must match.
+        let mut parent = (0..height * width).collect::<Vec<_>>();
+        for y in 0..height {
+            for x in 0..width {
+                for line in [clean, smudged] {
+                    if let Some((mx, my)) = line.mirror((x, y), height, width) {
+                        let (a, b) = (
+                            root(&mut parent, y * width + x),
+                            root(&mut parent, my * width + mx),
+                        );
+                        parent[a] = b;
+                    }
+                }
+            }
+        }
+        let rocks = (0..height * width)
+            .map(|_| rng.chance(0.5))
+            .collect::<Vec<_>>();
+        let mut pattern = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| rocks[root(&mut parent, y * width + x)])
+                    .collect()
+            })
+            .collect::<Pattern>();
+
+        let smudges = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|&cell| {
+                clean.mirror(cell, height, width).is_none()
+                    && smudged.mirror(cell, height, width).is_some()
+            })
+            .collect::<Vec<_>>();
+        if smudges.is_empty() {
+            continue;
+        }
+        let (x, y) = *rng.pick(&smudges);
+        pattern[y][x] = !pattern[y][x];
+
+        let lines = (1..height)
+            .map(Line::Row)
+            .chain((1..width).map(Line::Column))
+            .map(|line| (line, mismatches(&pattern, line)))
+            .filter(|&(_, mismatches)| mismatches <= 1)
+            .collect::<Vec<_>>();
+        if lines == [(clean, 0), (smudged, 1)] || lines == [(smudged, 1), (clean, 0)] {
+            return (pattern, clean, smudged);
+        }
+    }
+}
+
+/// The representative of `cell`'s set in a union-find forest.
+fn root(parent: &mut [usize], mut cell: usize) -> usize {
+    while parent[cell] != cell {
+        parent[cell] = parent[parent[cell]];
+        cell = parent[cell];
+    }
+    cell
+}
+
+/// How many cells differ from their mirror image across `line`, counting each pair once.
+fn mismatches(pattern: &Pattern, line: Line) -> usize {
+    let (height, width) = (pattern.len(), pattern[0].len());
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter_map(|cell| {
+            line.mirror(cell, height, width)
+                .map(|mirror| (cell, mirror))
+        })
+        .filter(|&((x, y), (mx, my))| pattern[y][x] != pattern[my][mx])
+        .count()
+        / 2
+}
+
+fn render(pattern: &Pattern) -> String {
+    pattern
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&rock| if rock { '#' } else { '.' })
+                .collect()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{generate, run, Part};
+
+    fn summary(line: Line) -> usize {
+        match line {
+            Line::Row(row) => 100 * row,
+            Line::Column(column) => column,
+        }
+    }
+
+    #[test]
+    fn finds_the_intended_lines() -> miette::Result<()> {
+        let mut rng = Rng::new(13);
+        for _ in 0..50 {
+            let (pattern, clean, smudged) = pattern(&mut rng);
+            let input = render(&pattern);
+            assert_eq!(
+                summary(clean).to_string(),
+                run::<Day13>(&input, Part::One)?.value
+            );
+            assert_eq!(
+                summary(smudged).to_string(),
+                run::<Day13>(&input, Part::Two)?.value
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn generates_a_pattern_per_size() {
+        assert_eq!(100, generate::<Day13>(100, 13).split("\n\n").count());
+    }
+}
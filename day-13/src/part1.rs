@@ -1,31 +1,12 @@
 use crate::custom_error::AocError;
 use crate::shared::{Notes, Reflection};
-use itertools::Itertools;
-use std::str::FromStr;
+use crate::Day13;
+use aoc_core::Solution;
 
-pub struct Pattern {
-    pub cols: Vec<String>,
-    pub rows: Vec<String>,
-}
-
-impl FromStr for Pattern {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rows = s.lines().map(|line| line.to_string()).collect_vec();
-        let cols = (0..rows[0].len())
-            .map(|col| {
-                rows.iter()
-                    .map(|row| row.chars().nth(col).unwrap())
-                    .collect::<String>()
-            })
-            .collect_vec();
-
-        Ok(Self { cols, rows })
-    }
-}
+/// Mirrors that must match exactly.
+pub struct Clean;
 
-impl Reflection for Pattern {
+impl Reflection for Clean {
     fn is_perfect_reflection(pattern: &[String], coords: (usize, usize)) -> bool {
         let mut cols = coords.0;
         let mut rows = coords.1;
@@ -63,21 +44,12 @@ impl Reflection for Pattern {
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    Ok(input
-        .parse::<Notes<Pattern>>()
-        .unwrap()
-        .patterns
-        .iter()
-        .map(|pattern| {
-            let cols = Pattern::find_reflection(&pattern.cols);
-            let rows = Pattern::find_reflection(&pattern.rows);
-
-            assert!(cols == 0 || rows == 0, "Pattern is not a reflection");
-            assert!(cols != 0 || rows != 0, "Pattern is not a reflection");
+    solve(&Day13::parse(input)?)
+}
 
-            cols + (100 * rows)
-        })
-        .sum())
+#[tracing::instrument(skip(notes))]
+pub fn solve(notes: &Notes) -> Result<usize, AocError> {
+    notes.summarize::<Clean>()
 }
 
 #[cfg(test)]
@@ -104,4 +76,9 @@ mod tests {
         assert_eq!(405, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn patterns_without_a_reflection_are_rejected() {
+        assert!(process("#.\n.#").is_err());
+    }
 }
@@ -1,29 +1,8 @@
 use crate::custom_error::AocError;
 use crate::shared::{Notes, Reflection};
+use crate::Day13;
+use aoc_core::Solution;
 use itertools::Itertools;
-use std::str::FromStr;
-
-pub struct Pattern {
-    pub cols: Vec<String>,
-    pub rows: Vec<String>,
-}
-
-impl FromStr for Pattern {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rows = s.lines().map(|line| line.to_string()).collect_vec();
-        let cols = (0..rows[0].len())
-            .map(|col| {
-                rows.iter()
-                    .map(|row| row.chars().nth(col).unwrap())
-                    .collect::<String>()
-            })
-            .collect_vec();
-
-        Ok(Self { cols, rows })
-    }
-}
 
 fn ne(pattern: &[String], cols: usize, rows: usize) -> usize {
     pattern[cols]
@@ -34,7 +13,10 @@ fn ne(pattern: &[String], cols: usize, rows: usize) -> usize {
         .len()
 }
 
-impl Reflection for Pattern {
+/// Mirrors with exactly one smudge that has to be cleaned off.
+pub struct Smudged;
+
+impl Reflection for Smudged {
     fn is_perfect_reflection(pattern: &[String], coords: (usize, usize)) -> bool {
         let mut cols = coords.0;
         let mut rows = coords.1;
@@ -83,21 +65,12 @@ impl Reflection for Pattern {
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    Ok(input
-        .parse::<Notes<Pattern>>()
-        .unwrap()
-        .patterns
-        .iter()
-        .map(|pattern| {
-            let cols = Pattern::find_reflection(&pattern.cols);
-            let rows = Pattern::find_reflection(&pattern.rows);
-
-            assert!(cols == 0 || rows == 0, "Pattern is not a reflection");
-            assert!(cols != 0 || rows != 0, "Pattern is not a reflection");
-
-            cols + (100 * rows)
-        })
-        .sum())
+    solve(&Day13::parse(input)?)
+}
+
+#[tracing::instrument(skip(notes))]
+pub fn solve(notes: &Notes) -> Result<usize, AocError> {
+    notes.summarize::<Smudged>()
 }
 
 #[cfg(test)]
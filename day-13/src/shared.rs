@@ -1,3 +1,5 @@
+use crate::custom_error::AocError;
+use aoc_grid::{Grid, GridError};
 use itertools::Itertools;
 use std::str::FromStr;
 
@@ -6,25 +8,60 @@ pub trait Reflection {
     fn find_reflection(pattern: &[String]) -> usize;
 }
 
-pub struct Notes<T>
-where
-    T: Reflection,
-{
-    pub patterns: Vec<T>,
+pub struct Pattern {
+    pub cols: Vec<String>,
+    pub rows: Vec<String>,
 }
 
-impl<T> FromStr for Notes<T>
-where
-    T: Reflection + FromStr<Err = &'static str>,
-{
-    type Err = &'static str;
+impl FromStr for Pattern {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::<char>::parse(s)?;
+        let rows = grid
+            .rows()
+            .map(|row| row.iter().collect::<String>())
+            .collect_vec();
+        let cols = grid
+            .columns()
+            .map(|col| col.collect::<String>())
+            .collect_vec();
+
+        Ok(Self { cols, rows })
+    }
+}
+
+pub struct Notes {
+    pub patterns: Vec<Pattern>,
+}
+
+impl Notes {
+    /// Sums the reflection lines found by `R`: columns left of a vertical line, 100 per row above
+    /// a horizontal one.
+    pub fn summarize<R: Reflection>(&self) -> Result<usize, AocError> {
+        self.patterns
+            .iter()
+            .enumerate()
+            .map(|(i, pattern)| {
+                let cols = R::find_reflection(&pattern.cols);
+                let rows = R::find_reflection(&pattern.rows);
+
+                match (cols, rows) {
+                    (cols, 0) if cols > 0 => Ok(cols),
+                    (0, rows) if rows > 0 => Ok(100 * rows),
+                    _ => Err(AocError::NoReflection(i + 1)),
+                }
+            })
+            .sum()
+    }
+}
+
+impl FromStr for Notes {
+    type Err = GridError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            patterns: s
-                .split("\n\n")
-                .map(|pattern| pattern.parse().unwrap())
-                .collect_vec(),
+            patterns: s.split("\n\n").map(str::parse).collect::<Result<_, _>>()?,
         })
     }
 }
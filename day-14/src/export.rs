@@ -0,0 +1,114 @@
+use crate::part1::{total_load, CUBE, ROUNDED};
+use crate::part2::{north_load, rounded_rocks, spin, SPINS};
+use crate::Day14;
+use aoc_algo::state_after;
+use aoc_core::Part;
+use aoc_grid::Grid;
+use aoc_visual::{Color, Drawing, Export, Shape};
+
+/// The platform the load is measured on: tilted north for part 1, after the last spin for
+/// part 2.
+impl Export for Day14 {
+    fn export(grid: &Self::Input, part: Part) -> Result<Drawing, Self::Error> {
+        let (title, platform) = match part {
+            Part::One => {
+                let mut tilted = grid.clone();
+                let load = total_load(&mut tilted);
+                (format!("tilted north, load {load}"), tilted)
+            }
+            Part::Two => {
+                let last = state_after(grid.clone(), SPINS, spin, rounded_rocks);
+                (format!("spin {SPINS}, load {}", north_load(&last)), last)
+            }
+        };
+        Ok(draw(title, &platform))
+    }
+}
+
+/// Rounded rocks as balls and cube-shaped rocks as squares, one tile to a unit.
+fn draw(title: String, platform: &Grid<char>) -> Drawing {
+    let mut drawing = Drawing::new(title, platform.width() as f64, platform.height() as f64);
+    for (point, &c) in platform.enumerate() {
+        let (x, y) = (point.x as f64, point.y as f64);
+        match c {
+            ROUNDED => drawing.push(Shape::Circle {
+                x: x + 0.5,
+                y: y + 0.5,
+                radius: 0.4,
+                fill: Color::ACCENT,
+            }),
+            // A little smaller than their tile, so that walls of them still show their blocks.
+            CUBE => drawing.push(Shape::Rect {
+                x: x + 0.05,
+                y: y + 0.05,
+                width: 0.9,
+                height: 0.9,
+                fill: Color::SOLID,
+            }),
+            _ => {}
+        }
+    }
+    drawing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+
+    #[test]
+    fn draws_the_tilted_platform() -> miette::Result<()> {
+        let drawing = Day14::export(&Day14::parse("O#\n.O\nO.")?, Part::One)?;
+
+        assert_eq!("tilted north, load 7", drawing.title);
+        assert_eq!((2.0, 3.0), (drawing.width, drawing.height));
+        assert_eq!(
+            vec![
+                Shape::Circle {
+                    x: 0.5,
+                    y: 0.5,
+                    radius: 0.4,
+                    fill: Color::ACCENT
+                },
+                Shape::Rect {
+                    x: 1.05,
+                    y: 0.05,
+                    width: 0.9,
+                    height: 0.9,
+                    fill: Color::SOLID
+                },
+                Shape::Circle {
+                    x: 0.5,
+                    y: 1.5,
+                    radius: 0.4,
+                    fill: Color::ACCENT
+                },
+                Shape::Circle {
+                    x: 1.5,
+                    y: 1.5,
+                    radius: 0.4,
+                    fill: Color::ACCENT
+                },
+            ],
+            drawing.shapes
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn draws_the_platform_after_the_last_spin() -> miette::Result<()> {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let drawing = Day14::export(&Day14::parse(input)?, Part::Two)?;
+        assert_eq!("spin 1000000000, load 64", drawing.title);
+        Ok(())
+    }
+}
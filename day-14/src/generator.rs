@@ -0,0 +1,21 @@
+use crate::Day14;
+use aoc_core::{Generator, Rng};
+
+/// A `size` by `size` platform with rounded rocks (`O`) and cube-shaped rocks (`#`) scattered
+/// over it in about the puzzle's proportions.
+impl Generator for Day14 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.range(0..=99) {
+                        0..=19 => 'O',
+                        20..=34 => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
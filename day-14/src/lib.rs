@@ -1,4 +1,35 @@
+use crate::custom_error::AocError;
+use aoc_core::Solution;
+use aoc_grid::Grid;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+#[cfg(feature = "visual")]
+mod export;
+mod generator;
 pub mod part1;
 pub mod part2;
+#[cfg(feature = "visual")]
+mod visualize;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Grid<char>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,24 +1,28 @@
 use crate::custom_error::AocError;
-use itertools::Itertools;
+use crate::Day14;
+use aoc_core::Solution;
+use aoc_grid::{Grid, Point};
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let mut grid = input
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect_vec();
+    solve(&Day14::parse(input)?)
+}
+
+#[tracing::instrument(skip(grid))]
+pub fn solve(grid: &Grid<char>) -> Result<usize, AocError> {
+    let mut grid = grid.clone();
 
     let total = total_load(&mut grid);
 
     Ok(total)
 }
 
-fn total_load(grid: &mut [Vec<char>]) -> usize {
+pub(crate) fn total_load(grid: &mut Grid<char>) -> usize {
     let mut total = 0;
 
-    for y in 0..grid.len() {
-        for x in 0..grid[y].len() {
-            if grid[y][x] == ROUNDED {
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            if grid[Point::new(x, y)] == ROUNDED {
                 total += roll_north(grid, x, y);
             }
         }
@@ -27,26 +31,26 @@ fn total_load(grid: &mut [Vec<char>]) -> usize {
     total
 }
 
-fn roll_north(grid: &mut [Vec<char>], x: usize, y: usize) -> usize {
+fn roll_north(grid: &mut Grid<char>, x: usize, y: usize) -> usize {
     let mut pos = y;
 
     for y in (1..=y).rev() {
-        match grid[y - 1][x] {
+        match grid[Point::new(x, y - 1)] {
             CUBE | ROUNDED => break,
             _ => {
-                grid[y - 1][x] = ROUNDED;
-                grid[y][x] = EMPTY;
+                grid[Point::new(x, y - 1)] = ROUNDED;
+                grid[Point::new(x, y)] = EMPTY;
                 pos -= 1;
             }
         }
     }
 
-    grid.len() - pos
+    grid.height() - pos
 }
 
-const ROUNDED: char = 'O';
-const CUBE: char = '#';
-const EMPTY: char = '.';
+pub(crate) const ROUNDED: char = 'O';
+pub(crate) const CUBE: char = '#';
+pub(crate) const EMPTY: char = '.';
 
 #[cfg(test)]
 mod tests {
@@ -1,17 +1,83 @@
 use crate::custom_error::AocError;
+use crate::part1::{EMPTY, ROUNDED};
+use crate::Day14;
+use aoc_algo::state_after;
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Point};
+
+pub(crate) const SPINS: usize = 1_000_000_000;
 
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<usize, AocError> {
-    todo!("day 01 - part 1");
+pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    solve(&Day14::parse(input)?)
+}
+
+#[tracing::instrument(skip(grid))]
+pub fn solve(grid: &Grid<char>) -> Result<usize, AocError> {
+    let grid = state_after(grid.clone(), SPINS, spin, rounded_rocks);
+
+    Ok(north_load(&grid))
+}
+
+/// Tilts the platform north, west, south and then east.
+pub(crate) fn spin(mut grid: Grid<char>) -> Grid<char> {
+    for dir in [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ] {
+        tilt(&mut grid, dir);
+    }
+    grid
+}
+
+/// Rolls every rounded rock as far as it goes towards `dir`, starting with the rocks nearest
+/// that edge so that the others pile up behind them.
+fn tilt(grid: &mut Grid<char>, dir: Direction) {
+    let mut positions = grid.positions().collect::<Vec<_>>();
+    if matches!(dir, Direction::Down | Direction::Right) {
+        positions.reverse();
+    }
+
+    for pos in positions {
+        if grid[pos] != ROUNDED {
+            continue;
+        }
+
+        let mut end = pos;
+        while let Some(next) = grid.step(end, dir).filter(|&next| grid[next] == EMPTY) {
+            end = next;
+        }
+        grid[pos] = EMPTY;
+        grid[end] = ROUNDED;
+    }
+}
+
+/// Which cells hold a rounded rock, one bit each. The cube-shaped rocks never move, so this
+/// tells platforms apart in a fraction of the space the grid takes.
+pub(crate) fn rounded_rocks(grid: &Grid<char>) -> Vec<u64> {
+    let mut bits = vec![0; (grid.width() * grid.height()).div_ceil(64)];
+    for (i, &c) in grid.iter().enumerate() {
+        if c == ROUNDED {
+            bits[i / 64] |= 1 << (i % 64);
+        }
+    }
+    bits
+}
+
+pub(crate) fn north_load(grid: &Grid<char>) -> usize {
+    grid.enumerate()
+        .filter(|&(_, &c)| c == ROUNDED)
+        .map(|(Point { y, .. }, _)| grid.height() - y)
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
-    //use super::*;
+    use super::*;
 
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let _input = "O....#....
+    const INPUT: &str = "O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -21,7 +87,27 @@ O.#..O.#.#
 .......O..
 #....###..
 #OO..#....";
-        //assert_eq!(64, process(input)?);
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        assert_eq!(64, process(INPUT)?);
+        Ok(())
+    }
+
+    #[test]
+    fn spins_like_the_example() -> miette::Result<()> {
+        let after_three = ".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O";
+        let grid = (0..3).fold(Day14::parse(INPUT)?, |grid, _| spin(grid));
+        assert_eq!(Day14::parse(after_three)?, grid);
         Ok(())
     }
 }
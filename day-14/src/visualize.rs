@@ -0,0 +1,128 @@
+use crate::part1::{total_load, CUBE, ROUNDED};
+use crate::part2::{north_load, rounded_rocks, spin, SPINS};
+use crate::Day14;
+use aoc_algo::find_cycle;
+use aoc_core::Part;
+use aoc_grid::Grid;
+use aoc_visual::{Cell, Color, Scene, Visualize};
+
+/// The platform before it is tilted, then after. Part 2 shows every spin until the rocks come
+/// back to an earlier arrangement, and then the platform after the last spin.
+impl Visualize for Day14 {
+    fn visualize(
+        grid: &Self::Input,
+        part: Part,
+        show: &mut dyn FnMut(Scene),
+    ) -> Result<(), Self::Error> {
+        show(Scene::new("before tilting", draw(grid)));
+
+        match part {
+            Part::One => {
+                let mut tilted = grid.clone();
+                let load = total_load(&mut tilted);
+                show(Scene::new(
+                    format!("tilted north, load {load}"),
+                    draw(&tilted),
+                ));
+            }
+            Part::Two => {
+                // Every arrangement from the start, so the one after the last spin can be
+                // looked up in the cycle instead of spinning towards it a second time.
+                let mut states = vec![grid.clone()];
+                let (cycle, _) = find_cycle(
+                    grid.clone(),
+                    |grid| {
+                        let grid = spin(grid);
+                        states.push(grid.clone());
+                        grid
+                    },
+                    rounded_rocks,
+                );
+
+                for (spins, grid) in states.iter().enumerate().skip(1) {
+                    show(Scene::new(
+                        format!("spin {spins}, load {}", north_load(grid)),
+                        draw(grid),
+                    ));
+                }
+
+                let last = &states[cycle.reduce(SPINS)];
+                show(Scene::new(
+                    format!(
+                        "spin {SPINS}, load {}, repeating every {} spins from spin {}",
+                        north_load(last),
+                        cycle.length,
+                        cycle.start
+                    ),
+                    draw(last),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn draw(grid: &Grid<char>) -> Grid<Cell> {
+    grid.map(|&c| match c {
+        ROUNDED => Cell::new('●').fg(Color::ACCENT),
+        CUBE => Cell::new('█').fg(Color::SOLID),
+        _ => Cell::new('·').fg(Color::DIM),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use aoc_visual::to_ansi;
+
+    const INPUT: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    fn titles(part: Part) -> miette::Result<Vec<String>> {
+        let mut titles = Vec::new();
+        Day14::visualize(&Day14::parse(INPUT)?, part, &mut |scene| {
+            titles.push(scene.title)
+        })?;
+        Ok(titles)
+    }
+
+    #[test]
+    fn tilts_north() -> miette::Result<()> {
+        let mut frames = Vec::new();
+        Day14::visualize(&Day14::parse(INPUT)?, Part::One, &mut |scene| {
+            frames.push(to_ansi(&scene, false))
+        })?;
+
+        assert_eq!(2, frames.len());
+        assert!(frames[1].starts_with(
+            "tilted north, load 136
+●●●●·█·●··
+●●··█····█
+●●··●██··●
+"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn shows_spins_until_they_repeat() -> miette::Result<()> {
+        let titles = titles(Part::Two)?;
+        // The example repeats the arrangement after spin 3 every 7 spins.
+        assert_eq!(1 + 10 + 1, titles.len());
+        assert_eq!("spin 1, load 87", titles[1]);
+        assert_eq!(
+            "spin 1000000000, load 64, repeating every 7 spins from spin 3",
+            titles[11]
+        );
+        Ok(())
+    }
+}
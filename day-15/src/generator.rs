@@ -0,0 +1,38 @@
+use crate::Day15;
+use aoc_core::{Generator, Rng};
+
+/// `size` initialization steps over a pool of labels small enough that lenses get replaced
+/// and removed again, like in the puzzle.
+impl Generator for Day15 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let labels = (0..size.div_ceil(4).max(1))
+            .map(|_| {
+                (0..rng.range(2..=6))
+                    .map(|_| char::from(rng.range(b'a'..=b'z')))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        (0..size)
+            .map(|_| {
+                let label = rng.pick(&labels);
+                match rng.chance(0.6) {
+                    true => format!("{label}={}", rng.range(1..=9)),
+                    false => format!("{label}-"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate;
+
+    #[test]
+    fn generates_a_step_per_size() {
+        assert_eq!(4000, generate::<Day15>(4000, 15).split(',').count());
+    }
+}
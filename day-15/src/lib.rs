@@ -1,6 +1,61 @@
 extern crate core;
 
+use crate::custom_error::AocError;
+use aoc_core::{ParseError, Solution};
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
 pub mod part1;
 pub mod part2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    /// The comma separated initialization steps, newlines stripped.
+    type Input = Vec<String>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .split(',')
+            .map(|step| {
+                let step = step.trim();
+                check_step(input, step)?;
+                Ok(step.to_owned())
+            })
+            .collect::<Result<_, ParseError>>()?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
+
+/// Steps either remove a lens (`cm-`) or put one in with a focal length from 1 to 9 (`rn=1`).
+fn check_step(input: &str, step: &str) -> Result<(), ParseError> {
+    let valid = match step.split_once('=') {
+        Some((label, focal_length)) => {
+            !label.is_empty() && matches!(focal_length.parse::<usize>(), Ok(1..=9))
+        }
+        None => step
+            .strip_suffix('-')
+            .is_some_and(|label| !label.is_empty()),
+    };
+
+    match valid {
+        true => Ok(()),
+        false => Err(ParseError::at(
+            input,
+            step,
+            "expected `<label>=<focal length>` or `<label>-`",
+        )),
+    }
+}
@@ -1,4 +1,6 @@
 use crate::custom_error::AocError;
+use crate::Day15;
+use aoc_core::Solution;
 
 pub fn hash(input: &str) -> usize {
     let mut acc = 0;
@@ -10,7 +12,12 @@ pub fn hash(input: &str) -> usize {
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    Ok(input.split(',').map(|s| s.trim()).map(hash).sum())
+    solve(&Day15::parse(input)?)
+}
+
+#[tracing::instrument(skip(steps))]
+pub fn solve(steps: &[String]) -> Result<usize, AocError> {
+    Ok(steps.iter().map(|step| hash(step)).sum())
 }
 
 #[cfg(test)]
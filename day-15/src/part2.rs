@@ -1,12 +1,19 @@
 use crate::custom_error::AocError;
 use crate::part1::hash;
+use crate::Day15;
+use aoc_core::Solution;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
+    solve(&Day15::parse(input)?)
+}
+
+#[tracing::instrument(skip(steps))]
+pub fn solve(steps: &[String]) -> Result<usize, AocError> {
     let mut map: HashMap<usize, Box> = HashMap::new();
-    for label in input.split(',') {
+    for label in steps {
         apply_operation(&mut map, label);
     }
     Ok(focusing_power(&map))
@@ -59,7 +66,13 @@ impl<'a> From<&'a str> for Label<'a> {
         let code = parts.next().unwrap().trim_end_matches('-');
 
         let operation = match parts.next() {
-            Some(value) => Operation::Equals(Cow::Borrowed(code), value.trim().parse().unwrap()),
+            Some(value) => Operation::Equals(
+                Cow::Borrowed(code),
+                value
+                    .trim()
+                    .parse()
+                    .expect("focal lengths are checked while parsing"),
+            ),
             None => Operation::Dash(Cow::Borrowed(code)),
         };
 
@@ -100,6 +113,7 @@ impl Box {
         }
     }
 
+    #[cfg(test)]
     fn new(lenses: Vec<(String, usize)>) -> Self {
         Self { lenses }
     }
@@ -208,4 +222,10 @@ mod tests {
 
         assert_eq!(focusing_power(&map), 145);
     }
+
+    #[test]
+    fn focal_lengths_go_up_to_nine() {
+        assert!(process("rn=10").is_err());
+        assert!(process("rn=0").is_err());
+    }
 }
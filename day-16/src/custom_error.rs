@@ -2,10 +2,11 @@ use miette::Diagnostic;
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
-pub enum AocError<'a> {
+pub enum AocError {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
-    #[error("{0}")]
-    New(&'a str),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    GridError(#[from] aoc_grid::GridError),
 }
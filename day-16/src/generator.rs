@@ -0,0 +1,21 @@
+use crate::Day16;
+use aoc_core::{Generator, Rng};
+
+const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+/// A `size` by `size` contraption with about one mirror or splitter in ten tiles.
+impl Generator for Day16 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.chance(0.1) {
+                        true => *rng.pick(&DEVICES),
+                        false => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -1,4 +1,36 @@
+use crate::custom_error::AocError;
+use crate::part1::Contraption;
+use aoc_core::Solution;
+use aoc_grid::Grid;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+mod generator;
 pub mod part1;
 pub mod part2;
+#[cfg(feature = "visual")]
+mod visualize;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Contraption;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Contraption {
+            tiles: Grid::parse(input)?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,26 +1,28 @@
 use crate::custom_error::AocError;
-use itertools::Itertools;
+use crate::Day16;
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Point};
 use std::collections::{HashSet, VecDeque};
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let grid = input
-        .lines()
-        .map(|line| line.chars().map(TileType::from).collect())
-        .collect_vec()
-        .to_grid();
+    solve(&Day16::parse(input)?)
+}
 
-    Ok(grid.energize(0, 0, Direction::Right))
+#[tracing::instrument(skip(contraption))]
+pub fn solve(contraption: &Contraption) -> Result<usize, AocError> {
+    Ok(contraption.energize(Point::new(0, 0), Direction::Right))
 }
 
-impl Grid {
-    pub fn energize(&self, x: usize, y: usize, dir: Direction) -> usize {
+impl Contraption {
+    pub fn energize(&self, start: Point, dir: Direction) -> usize {
+        if !self.tiles.contains(start) {
+            return 0;
+        }
+
         let mut memo: HashSet<Beam> = HashSet::new();
         let mut energized = HashSet::new();
-        let mut queue = VecDeque::from([Beam {
-            pos: Point { x, y },
-            dir,
-        }]);
+        let mut queue = VecDeque::from([Beam { pos: start, dir }]);
 
         while let Some(beam) = queue.pop_front() {
             match memo.contains(&beam) {
@@ -35,9 +37,9 @@ impl Grid {
         energized.len()
     }
 
-    fn reflect_beam(&self, dir: &Direction, x: &usize, y: &usize) -> Vec<Direction> {
-        match (&self.tiles[*y][*x], dir) {
-            (TileType::Empty, dir) => vec![dir.clone()],
+    fn reflect_beam(&self, dir: Direction, pos: Point) -> Vec<Direction> {
+        match (&self.tiles[pos], dir) {
+            (TileType::Empty, dir) => vec![dir],
             (TileType::Mirror(mirror_type), dir) => match (mirror_type, dir) {
                 (MirrorType::ForwardSlash, Direction::Up)
                 | (MirrorType::BackwardSlash, Direction::Down) => vec![Direction::Right],
@@ -53,68 +55,24 @@ impl Grid {
         }
     }
 
-    fn spread_beam(&self, queue: &mut VecDeque<Beam>, beam: &Beam) {
-        for dir in self.reflect_beam(&beam.dir, &beam.pos.x, &beam.pos.y) {
-            let b = Beam {
-                pos: Point {
-                    x: beam.pos.x,
-                    y: beam.pos.y,
-                },
-                dir: dir.clone(),
-            };
-            if let Some(beam) = b.forward(self.tiles[0].len(), self.tiles.len()) {
-                queue.push_back(beam);
+    pub(crate) fn spread_beam(&self, queue: &mut VecDeque<Beam>, beam: &Beam) {
+        for dir in self.reflect_beam(beam.dir, beam.pos) {
+            if let Some(pos) = self.tiles.step(beam.pos, dir) {
+                queue.push_back(Beam { pos, dir });
             }
         }
     }
 }
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
-struct Beam {
-    pos: Point,
-    dir: Direction,
-}
-
-impl Beam {
-    fn forward(mut self, x: usize, y: usize) -> Option<Self> {
-        match self.dir {
-            Direction::Up if self.pos.y > 0 => self.pos.y -= 1,
-            Direction::Down if self.pos.y < y - 1 => self.pos.y += 1,
-            Direction::Left if self.pos.x > 0 => self.pos.x -= 1,
-            Direction::Right if self.pos.x < x - 1 => self.pos.x += 1,
-            _ => return None,
-        }
-        Some(self)
-    }
-}
-
-#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
-struct Point {
-    x: usize,
-    y: usize,
+pub(crate) struct Beam {
+    pub(crate) pos: Point,
+    pub(crate) dir: Direction,
 }
 
 #[derive(Debug)]
-pub struct Grid {
-    pub(crate) tiles: Vec<Vec<TileType>>,
-}
-
-#[derive(Debug, Hash, Eq, PartialEq, Clone)]
-pub enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-pub trait IntoGrid {
-    fn to_grid(self) -> Grid;
-}
-
-impl IntoGrid for Vec<Vec<TileType>> {
-    fn to_grid(self) -> Grid {
-        Grid { tiles: self }
-    }
+pub struct Contraption {
+    pub(crate) tiles: Grid<TileType>,
 }
 
 #[derive(Debug)]
@@ -146,7 +104,6 @@ impl From<char> for TileType {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use itertools::Itertools;
     use tracing_subscriber::fmt;
 
     const INPUT: &str = r#".|...\....
@@ -163,23 +120,25 @@ mod tests {
 
     #[test]
     fn parse() -> miette::Result<()> {
-        INPUT
-            .lines()
-            .map(|line| line.chars().map(TileType::from).collect_vec())
-            .collect_vec()
-            .to_grid();
+        let tiles = Grid::<TileType>::parse(INPUT)?;
+        assert_eq!((10, 10), (tiles.width(), tiles.height()));
         Ok(())
     }
 
     #[test]
     fn energize() {
         fmt::init();
-        let grid = INPUT
-            .lines()
-            .map(|line| line.chars().map(TileType::from).collect_vec())
-            .collect_vec()
-            .to_grid();
+        let contraption = Contraption {
+            tiles: Grid::parse(INPUT).unwrap(),
+        };
+
+        assert_eq!(contraption.energize(Point::new(0, 0), Direction::Right), 46);
+    }
 
-        assert_eq!(grid.energize(0, 0, Direction::Right), 46);
+    #[test]
+    fn empty_contraptions_stay_dark() -> miette::Result<()> {
+        assert_eq!(0, process("")?);
+        assert_eq!(0, process("\n\n")?);
+        Ok(())
     }
 }
@@ -1,32 +1,32 @@
 use crate::custom_error::AocError;
-use crate::part1::{Direction, IntoGrid, TileType};
-use itertools::Itertools;
+use crate::part1::{Contraption, TileType};
+use crate::Day16;
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Point};
+
+/// Every tile on the edge with the direction a beam entering there heads in.
+pub(crate) fn entries(tiles: &Grid<TileType>) -> impl Iterator<Item = (Point, Direction)> + '_ {
+    tiles.positions().filter_map(|point| {
+        match (point.x, point.y) {
+            (0, _) => Some(Direction::Right),
+            (_, 0) => Some(Direction::Down),
+            (_, y) if y == tiles.height() - 1 => Some(Direction::Up),
+            (x, _) if x == tiles.width() - 1 => Some(Direction::Left),
+            _ => None,
+        }
+        .map(|dir| (point, dir))
+    })
+}
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let grid = input
-        .lines()
-        .map(|line| line.chars().map(TileType::from).collect())
-        .collect_vec()
-        .to_grid();
+    solve(&Day16::parse(input)?)
+}
 
-    let max_energized = grid
-        .tiles
-        .iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.iter().enumerate().filter_map(move |(x, _)| {
-                match (x, y) {
-                    (0, _) => Some(Direction::Right),
-                    (_, 0) => Some(Direction::Down),
-                    (_, y) if y == row.len() - 1 => Some(Direction::Up),
-                    (x, _) if x == row.len() - 1 => Some(Direction::Left),
-                    _ => None,
-                }
-                .map(|dir| (x, y, dir))
-            })
-        })
-        .map(|(x, y, dir)| grid.energize(x, y, dir))
+#[tracing::instrument(skip(contraption))]
+pub fn solve(contraption: &Contraption) -> Result<usize, AocError> {
+    let max_energized = entries(&contraption.tiles)
+        .map(|(point, dir)| contraption.energize(point, dir))
         .max();
 
     match max_energized {
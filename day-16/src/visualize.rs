@@ -0,0 +1,156 @@
+use crate::part1::{Beam, Contraption, MirrorType, TileType};
+use crate::part2::entries;
+use crate::Day16;
+use aoc_core::Part;
+use aoc_grid::{Direction, Grid, Point};
+use aoc_visual::{Cell, Color, Scene, Visualize};
+use std::collections::{HashSet, VecDeque};
+
+/// How many frames show the beam spreading before the last one.
+const FRAMES: usize = 20;
+
+/// The contraption with the tiles the beam energizes shaded by how soon it gets there, from
+/// dark for the first to bright for the last. Earlier frames show the beam spreading. Part 2
+/// shows the entry that energizes the most tiles.
+impl Visualize for Day16 {
+    fn visualize(
+        contraption: &Self::Input,
+        part: Part,
+        show: &mut dyn FnMut(Scene),
+    ) -> Result<(), Self::Error> {
+        let (start, dir) = match part {
+            Part::One => (Point::new(0, 0), Direction::Right),
+            Part::Two => match entries(&contraption.tiles)
+                .max_by_key(|&(point, dir)| contraption.energize(point, dir))
+            {
+                Some(entry) => entry,
+                None => return Ok(()),
+            },
+        };
+
+        let lit = lit_at(contraption, start, dir);
+        let last = lit.iter().flatten().copied().max().unwrap_or_default();
+        let energized = |until: usize| lit.iter().flatten().filter(|&&at| at <= until).count();
+
+        for frame in 1..FRAMES {
+            let until = last * frame / FRAMES;
+            show(Scene::new(
+                format!("step {until}: {} tiles energized", energized(until)),
+                draw(contraption, &lit, until, last),
+            ));
+        }
+        show(Scene::new(
+            format!(
+                "entering at {},{} heading {dir:?}: {} tiles energized",
+                start.x,
+                start.y,
+                energized(last)
+            ),
+            draw(contraption, &lit, last, last),
+        ));
+        Ok(())
+    }
+}
+
+/// How many steps the beam takes to first reach each tile, `None` for tiles it never reaches.
+fn lit_at(contraption: &Contraption, start: Point, dir: Direction) -> Grid<Option<usize>> {
+    let mut lit = contraption.tiles.map(|_| None);
+    if !contraption.tiles.contains(start) {
+        return lit;
+    }
+
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([(Beam { pos: start, dir }, 0)]);
+    while let Some((beam, step)) = queue.pop_front() {
+        if !seen.insert(beam.clone()) {
+            continue;
+        }
+        lit[beam.pos].get_or_insert(step);
+
+        let mut next = VecDeque::new();
+        contraption.spread_beam(&mut next, &beam);
+        queue.extend(next.into_iter().map(|beam| (beam, step + 1)));
+    }
+    lit
+}
+
+/// The tiles with those the beam reaches within `until` steps shaded, on a scale up to `last`.
+fn draw(
+    contraption: &Contraption,
+    lit: &Grid<Option<usize>>,
+    until: usize,
+    last: usize,
+) -> Grid<Cell> {
+    contraption.tiles.map_indexed(|point, tile| {
+        let cell = match tile {
+            TileType::Empty => Cell::new('·').fg(Color::DIM),
+            TileType::Mirror(mirror) => Cell::new(match mirror {
+                MirrorType::ForwardSlash => '╱',
+                MirrorType::BackwardSlash => '╲',
+                MirrorType::Vertical => '│',
+                MirrorType::Horizontal => '─',
+            })
+            .fg(Color::SOLID),
+        };
+        match lit[point] {
+            Some(at) if at <= until => {
+                cell.bg(Color::heat(0.25 + 0.75 * at as f64 / last.max(1) as f64))
+            }
+            _ => cell,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+
+    const INPUT: &str = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+"#;
+
+    fn frames(part: Part) -> miette::Result<Vec<Scene>> {
+        let mut frames = Vec::new();
+        Day16::visualize(&Day16::parse(INPUT)?, part, &mut |scene| frames.push(scene))?;
+        Ok(frames)
+    }
+
+    #[test]
+    fn shades_the_energized_tiles() -> miette::Result<()> {
+        let frames = frames(Part::One)?;
+        assert_eq!(FRAMES, frames.len());
+
+        let last = frames.last().unwrap();
+        assert_eq!(
+            "entering at 0,0 heading Right: 46 tiles energized",
+            last.title
+        );
+        assert_eq!(
+            46,
+            last.cells.iter().filter(|cell| cell.bg.is_some()).count()
+        );
+        assert_eq!(Some(Color::heat(0.25)), last.cells[Point::new(0, 0)].bg);
+        assert_eq!('╲', last.cells[Point::new(5, 0)].glyph);
+        Ok(())
+    }
+
+    #[test]
+    fn spreads_the_beam_over_the_frames() -> miette::Result<()> {
+        let shaded = frames(Part::Two)?
+            .iter()
+            .map(|scene| scene.cells.iter().filter(|cell| cell.bg.is_some()).count())
+            .collect::<Vec<_>>();
+        assert!(shaded.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(Some(&51), shaded.last());
+        Ok(())
+    }
+}
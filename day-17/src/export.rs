@@ -0,0 +1,70 @@
+use crate::{part1, part2, Day17};
+use aoc_core::Part;
+use aoc_visual::{Color, Drawing, Export, Shape, Stroke};
+
+/// The heat loss of every block as a heat map, with the path of the crucible that loses the
+/// least drawn over it as a line through the middles of the blocks it enters.
+impl Export for Day17 {
+    fn export(graph: &Self::Input, part: Part) -> Result<Drawing, Self::Error> {
+        let cheapest = match part {
+            Part::One => part1::cheapest_path(graph),
+            Part::Two => part2::cheapest_path(graph),
+        };
+        let title = match &cheapest {
+            Some((_, loss)) => format!("least heat loss: {loss}"),
+            None => "no path".to_string(),
+        };
+
+        let mut drawing = Drawing::new(title, graph.width() as f64, graph.height() as f64);
+        for (point, &loss) in graph.enumerate() {
+            drawing.push(Shape::Rect {
+                x: point.x as f64,
+                y: point.y as f64,
+                width: 1.0,
+                height: 1.0,
+                fill: Color::heat(f64::from(loss.saturating_sub(1)) / 8.0),
+            });
+        }
+
+        if let Some((path, _)) = cheapest {
+            let middle = |x: usize, y: usize| (x as f64 + 0.5, y as f64 + 0.5);
+            drawing.push(Shape::Polyline {
+                points: std::iter::once(middle(0, 0))
+                    .chain(path.iter().map(|(pos, _)| middle(pos.x, pos.y)))
+                    .collect(),
+                stroke: Stroke::new(Color::ACCENT, 3.0),
+            });
+        }
+        Ok(drawing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+
+    #[test]
+    fn draws_the_path_over_the_heat_map() -> miette::Result<()> {
+        let drawing = Day17::export(&Day17::parse("19\n11")?, Part::One)?;
+
+        assert_eq!("least heat loss: 2", drawing.title);
+        assert_eq!(5, drawing.shapes.len());
+        assert_eq!(
+            Some(&Shape::Polyline {
+                points: vec![(0.5, 0.5), (0.5, 1.5), (1.5, 1.5)],
+                stroke: Stroke::new(Color::ACCENT, 3.0),
+            }),
+            drawing.shapes.last()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn leaves_out_the_path_when_there_is_none() -> miette::Result<()> {
+        let drawing = Day17::export(&Day17::parse("5")?, Part::Two)?;
+        assert_eq!("no path", drawing.title);
+        assert_eq!(1, drawing.shapes.len());
+        Ok(())
+    }
+}
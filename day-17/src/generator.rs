@@ -0,0 +1,27 @@
+use crate::Day17;
+use aoc_core::{Generator, Rng};
+
+/// A `size` by `size` heat map of the digits 1 to 9, at least five blocks wide so the ultra
+/// crucible of part 2 can reach the factory.
+///
+/// Like in the puzzle, the heat loss runs higher towards the middle of the map.
+impl Generator for Day17 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let size = size.max(5);
+        let middle = (size - 1) as f64 / 2.0;
+
+        (0..size)
+            .map(|y| {
+                (0..size)
+                    .map(|x| {
+                        let from_middle = ((x as f64 - middle).abs() + (y as f64 - middle).abs())
+                            / (2.0 * middle);
+                        let high = 9 - (5.0 * from_middle) as u8;
+                        char::from(b'0' + rng.range(1..=high))
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
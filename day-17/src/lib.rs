@@ -1,49 +1,35 @@
+use crate::custom_error::AocError;
+use aoc_core::Solution;
+use aoc_grid::Grid;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+#[cfg(feature = "visual")]
+mod export;
+mod generator;
 pub mod part1;
 pub mod part2;
+#[cfg(feature = "visual")]
+mod visualize;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct Point {
-    x: usize,
-    y: usize,
-}
+pub struct Day17;
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+impl Solution for Day17 {
+    const DAY: u8 = 17;
 
-impl Direction {
-    fn opposite(&self) -> Self {
-        match self {
-            Self::Up => Self::Down,
-            Self::Down => Self::Up,
-            Self::Left => Self::Right,
-            Self::Right => Self::Left,
-        }
+    type Input = Grid<u32>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        part1::parse(input)
     }
-}
 
-impl Point {
-    fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
     }
-}
 
-impl Point {
-    fn next(&self, dir: &Direction, r: usize, c: usize) -> Option<Self> {
-        let new = match dir {
-            Direction::Up if self.x > 0 => Self::new(self.x - 1, self.y),
-            Direction::Down if self.x < (r - 1) => Self::new(self.x + 1, self.y),
-            Direction::Left if self.y > 0 => Self::new(self.x, self.y - 1),
-            Direction::Right if self.y < (c - 1) => Self::new(self.x, self.y + 1),
-            _ => return None,
-        };
-
-        Some(new)
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
     }
 }
@@ -1,38 +1,22 @@
 use crate::custom_error::AocError;
-use crate::{Direction, Point};
+use crate::Day17;
+use aoc_algo::dijkstra;
+use aoc_core::{check_chars, Solution};
+use aoc_grid::{Direction, Grid, Point};
 use itertools::Itertools;
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 struct Crucible {
-    loss: u32,
     pos: Point,
     dir: Direction,
     steps: u8,
 }
 
-impl Ord for Crucible {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.loss.cmp(&self.loss)
-    }
-}
-
-impl PartialOrd for Crucible {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 impl Crucible {
-    fn successors(&self, graph: &[Vec<u32>]) -> Vec<Self> {
+    /// Where the crucible can go next, with the heat lost on entering that block.
+    fn successors(&self, graph: &Grid<u32>) -> Vec<(Self, u32)> {
         let mut successors = Vec::new();
-        for dir in [
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ] {
+        for dir in Direction::ALL {
             if self.dir == dir && self.steps == 3 {
                 continue;
             }
@@ -41,15 +25,9 @@ impl Crucible {
                 continue;
             }
 
-            if let Some(pos) = self.pos.next(&dir, graph.len(), graph[0].len()) {
-                let loss = self.loss + graph[pos.x][pos.y];
+            if let Some(pos) = graph.step(self.pos, dir) {
                 let steps = if self.dir == dir { self.steps + 1 } else { 1 };
-                successors.push(Crucible {
-                    loss,
-                    pos,
-                    dir,
-                    steps,
-                });
+                successors.push((Crucible { pos, dir, steps }, graph[pos]));
             }
         }
         successors
@@ -58,55 +36,57 @@ impl Crucible {
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    if let Some(value) = dijkstra(input) {
-        return Ok(value);
+    solve(&Day17::parse(input)?)
+}
+
+#[tracing::instrument(skip(graph))]
+pub fn solve(graph: &Grid<u32>) -> Result<u32, AocError> {
+    if let Some((_, loss)) = cheapest_path(graph) {
+        return Ok(loss);
     }
 
     Ok(0)
 }
 
-fn dijkstra(input: &str) -> Option<u32> {
-    let graph = parse(input);
-    let goal = Point::new(graph.len() - 1, graph[0].len() - 1);
-
-    let mut queue = BinaryHeap::new();
-    let mut seen = HashSet::new();
-
-    let right = Crucible {
-        loss: graph[0][1],
-        pos: Point::new(0, 1),
-        dir: Direction::Right,
-        steps: 1,
-    };
-    let down = Crucible {
-        loss: graph[1][0],
-        pos: Point::new(1, 0),
-        dir: Direction::Down,
-        steps: 1,
-    };
-
-    queue.push(right);
-    queue.push(down);
-
-    while let Some(crucible) = queue.pop() {
-        if crucible.pos == goal {
-            return Some(crucible.loss);
-        }
-
-        for crucible in crucible.successors(&graph) {
-            if seen.insert((crucible.pos, crucible.dir, crucible.steps)) {
-                queue.push(crucible);
-            }
-        }
-    }
-    None
+/// The blocks the crucible with the least heat loss enters, in order and with the direction it
+/// enters them in, and the heat it loses on the way.
+pub(crate) fn cheapest_path(graph: &Grid<u32>) -> Option<(Vec<(Point, Direction)>, u32)> {
+    let goal = Point::new(
+        graph.width().checked_sub(1)?,
+        graph.height().checked_sub(1)?,
+    );
+
+    let starts = [Direction::Right, Direction::Down]
+        .into_iter()
+        .filter_map(|dir| {
+            let pos = graph.step(Point::new(0, 0), dir)?;
+            Some((Crucible { pos, dir, steps: 1 }, graph[pos]))
+        });
+
+    dijkstra(
+        starts,
+        |crucible| crucible.successors(graph),
+        |crucible| crucible.pos == goal,
+    )
+    .map(|(path, loss)| {
+        let path = path.iter().map(|crucible| (crucible.pos, crucible.dir));
+        (path.collect(), loss)
+    })
 }
 
-pub fn parse(input: &str) -> Vec<Vec<u32>> {
-    input
-        .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect_vec())
-        .collect_vec()
+pub fn parse(input: &str) -> Result<Grid<u32>, AocError> {
+    check_chars(
+        input,
+        input,
+        |c| c.is_ascii_digit() || c == '\n',
+        "expected a heat loss digit",
+    )?;
+
+    Ok(Grid::from_rows(input.lines().map(|line| {
+        line.chars()
+            .map(|c| c.to_digit(10).expect("digits are checked above"))
+            .collect_vec()
+    }))?)
 }
 
 #[cfg(test)]
@@ -131,4 +111,13 @@ mod tests {
         assert_eq!(102, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn tiny_maps_have_no_loss_or_no_path() -> miette::Result<()> {
+        assert_eq!(0, process("")?);
+        assert_eq!(0, process("5")?);
+        assert_eq!(6, process("56")?);
+        assert_eq!(7, process("5\n7")?);
+        Ok(())
+    }
 }
@@ -1,47 +1,25 @@
 use crate::custom_error::AocError;
-use crate::part1::parse;
-use crate::{Direction, Point};
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
+use crate::Day17;
+use aoc_algo::dijkstra;
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Point};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 struct UltraCrucible {
-    loss: u32,
     pos: Point,
     dir: Direction,
     steps: u8,
 }
 
-impl Ord for UltraCrucible {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.loss.cmp(&self.loss)
-    }
-}
-
-impl PartialOrd for UltraCrucible {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 impl UltraCrucible {
-    fn new(loss: u32, pos: Point, dir: Direction, steps: u8) -> Self {
-        Self {
-            loss,
-            pos,
-            dir,
-            steps,
-        }
+    fn new(pos: Point, dir: Direction, steps: u8) -> Self {
+        Self { pos, dir, steps }
     }
 
-    fn successors(&self, graph: &[Vec<u32>]) -> Vec<Self> {
+    /// Where the crucible can go next, with the heat lost on entering that block.
+    fn successors(&self, graph: &Grid<u32>) -> Vec<(Self, u32)> {
         let mut successors = Vec::new();
-        for dir in [
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ] {
+        for dir in Direction::ALL {
             if self.steps < 4 && dir != self.dir {
                 continue;
             }
@@ -53,52 +31,50 @@ impl UltraCrucible {
             if self.dir.opposite() == dir {
                 continue;
             }
-            if let Some(pos) = self.pos.next(&dir, graph.len(), graph[0].len()) {
-                let loss = self.loss + graph[pos.x][pos.y];
+            if let Some(pos) = graph.step(self.pos, dir) {
                 let steps = if self.dir == dir { self.steps + 1 } else { 1 };
-                successors.push(UltraCrucible {
-                    loss,
-                    pos,
-                    dir,
-                    steps,
-                });
+                successors.push((UltraCrucible::new(pos, dir, steps), graph[pos]));
             }
         }
         successors
     }
 }
 
-fn dijkstra(input: &str) -> Option<u32> {
-    let graph = parse(input);
-    let goal = Point::new(graph.len() - 1, graph[0].len() - 1);
-
-    let mut queue = BinaryHeap::new();
-    let mut seen = HashSet::new();
-
-    let right = UltraCrucible::new(graph[0][1], Point::new(0, 1), Direction::Right, 1);
-    let down = UltraCrucible::new(graph[1][0], Point::new(1, 0), Direction::Down, 1);
-
-    queue.push(right);
-    queue.push(down);
-
-    while let Some(crucible) = queue.pop() {
-        if crucible.pos == goal && crucible.steps >= 4 {
-            return Some(crucible.loss);
-        }
-
-        for crucible in crucible.successors(&graph) {
-            if seen.insert((crucible.pos, crucible.dir, crucible.steps)) {
-                queue.push(crucible);
-            }
-        }
-    }
-    None
+/// The blocks the crucible with the least heat loss enters, in order and with the direction it
+/// enters them in, and the heat it loses on the way.
+pub(crate) fn cheapest_path(graph: &Grid<u32>) -> Option<(Vec<(Point, Direction)>, u32)> {
+    let goal = Point::new(
+        graph.width().checked_sub(1)?,
+        graph.height().checked_sub(1)?,
+    );
+
+    let starts = [Direction::Right, Direction::Down]
+        .into_iter()
+        .filter_map(|dir| {
+            let pos = graph.step(Point::new(0, 0), dir)?;
+            Some((UltraCrucible::new(pos, dir, 1), graph[pos]))
+        });
+
+    dijkstra(
+        starts,
+        |crucible| crucible.successors(graph),
+        |crucible| crucible.pos == goal && crucible.steps >= 4,
+    )
+    .map(|(path, loss)| {
+        let path = path.iter().map(|crucible| (crucible.pos, crucible.dir));
+        (path.collect(), loss)
+    })
 }
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<u32, AocError> {
-    if let Some(value) = dijkstra(input) {
-        return Ok(value);
+    solve(&Day17::parse(input)?)
+}
+
+#[tracing::instrument(skip(graph))]
+pub fn solve(graph: &Grid<u32>) -> Result<u32, AocError> {
+    if let Some((_, loss)) = cheapest_path(graph) {
+        return Ok(loss);
     }
 
     Ok(0)
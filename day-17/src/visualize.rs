@@ -0,0 +1,91 @@
+use crate::{part1, part2, Day17};
+use aoc_core::Part;
+use aoc_grid::{Direction, Point};
+use aoc_visual::{Cell, Color, Scene, Visualize};
+
+/// The heat loss of every block as a heat map, with the path of the crucible that loses the
+/// least drawn over it in arrows.
+impl Visualize for Day17 {
+    fn visualize(
+        graph: &Self::Input,
+        part: Part,
+        show: &mut dyn FnMut(Scene),
+    ) -> Result<(), Self::Error> {
+        let cheapest = match part {
+            Part::One => part1::cheapest_path(graph),
+            Part::Two => part2::cheapest_path(graph),
+        };
+
+        let mut cells = graph.map(|&loss| {
+            let glyph = char::from_digit(loss, 10).unwrap_or('?');
+            Cell::new(glyph)
+                .fg(Color::rgb(235, 235, 235))
+                .bg(Color::heat(f64::from(loss.saturating_sub(1)) / 8.0))
+        });
+
+        let title = match cheapest {
+            Some((path, loss)) => {
+                let start = Point::new(0, 0);
+                cells[start] = Cell::new('●').fg(Color::DIM).bg(Color::ACCENT);
+                for (pos, dir) in path {
+                    let arrow = match dir {
+                        Direction::Up => '↑',
+                        Direction::Right => '→',
+                        Direction::Down => '↓',
+                        Direction::Left => '←',
+                    };
+                    cells[pos] = Cell::new(arrow).fg(Color::DIM).bg(Color::ACCENT);
+                }
+                format!("least heat loss: {loss}")
+            }
+            None => "no path".to_string(),
+        };
+        show(Scene::new(title, cells));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+    use aoc_grid::Grid;
+    use aoc_visual::to_ansi;
+
+    fn frames(input: &str, part: Part) -> miette::Result<Vec<String>> {
+        let graph: Grid<u32> = Day17::parse(input)?;
+        let mut frames = Vec::new();
+        Day17::visualize(&graph, part, &mut |scene| {
+            frames.push(to_ansi(&scene, false))
+        })?;
+        Ok(frames)
+    }
+
+    #[test]
+    fn draws_the_path_over_the_heat_map() -> miette::Result<()> {
+        let input = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+        assert_eq!(
+            vec![
+                "least heat loss: 71
+●→→→→→→→1111
+9999999↓9991
+9999999↓9991
+9999999↓9991
+9999999↓→→→→
+"
+            ],
+            frames(input, Part::Two)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn says_when_there_is_no_path() -> miette::Result<()> {
+        assert_eq!(vec!["no path\n5\n"], frames("5", Part::One)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::part1::{vertices, Area};
+use crate::Day18;
+use aoc_core::Part;
+use aoc_visual::{Color, Drawing, Export, Shape, Stroke};
+
+/// The lagoon as the polygon its trench outlines, which for the corrected plan is millions of
+/// meters across, far too big for a terminal.
+impl Export for Day18 {
+    fn export(plan: &Self::Input, part: Part) -> Result<Drawing, Self::Error> {
+        let commands = match part {
+            Part::One => &plan.commands,
+            Part::Two => &plan.corrected,
+        };
+        let corners = vertices(commands);
+
+        let (xs, ys): (Vec<_>, Vec<_>) = corners.iter().copied().unzip();
+        let min_x = xs.iter().min().copied().unwrap_or_default();
+        let min_y = ys.iter().min().copied().unwrap_or_default();
+        // Each corner is a cubic meter of trench, so the outline runs through their middles.
+        let width = (xs.iter().max().copied().unwrap_or_default() - min_x + 1) as f64;
+        let height = (ys.iter().max().copied().unwrap_or_default() - min_y + 1) as f64;
+        let margin = (width.max(height) / 40.0).max(1.0);
+
+        let title = match commands.area() {
+            Some(area) => format!("lagoon of {area} cubic meters"),
+            None => "lagoon too big to measure".to_string(),
+        };
+        let mut drawing = Drawing::new(title, width + 2.0 * margin, height + 2.0 * margin);
+        drawing.push(Shape::Polygon {
+            points: corners
+                .iter()
+                .map(|&(x, y)| {
+                    (
+                        (x - min_x) as f64 + 0.5 + margin,
+                        (y - min_y) as f64 + 0.5 + margin,
+                    )
+                })
+                .collect(),
+            fill: Color::FILL,
+            stroke: Some(Stroke::new(Color::ACCENT, 2.0)),
+        });
+        Ok(drawing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Solution;
+
+    #[test]
+    fn outlines_the_trench_through_its_middle() -> miette::Result<()> {
+        let plan = Day18::parse("R 2 (#000020)\nD 2 (#000021)\nL 2 (#000022)\nU 2 (#000023)")?;
+        let drawing = Day18::export(&plan, Part::One)?;
+
+        assert_eq!("lagoon of 9 cubic meters", drawing.title);
+        assert_eq!((5.0, 5.0), (drawing.width, drawing.height));
+        assert_eq!(
+            vec![Shape::Polygon {
+                points: vec![(3.5, 1.5), (3.5, 3.5), (1.5, 3.5), (1.5, 1.5)],
+                fill: Color::FILL,
+                stroke: Some(Stroke::new(Color::ACCENT, 2.0)),
+            }],
+            drawing.shapes
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn draws_the_corrected_plan_for_part_2() -> miette::Result<()> {
+        let plan = Day18::parse(
+            "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)",
+        )?;
+        let drawing = Day18::export(&plan, Part::Two)?;
+
+        assert_eq!("lagoon of 952408144115 cubic meters", drawing.title);
+        // 1186328 meters across, with a 40th of that as margin on either side.
+        assert_eq!(1186329.0 * 1.05, drawing.width);
+        Ok(())
+    }
+}
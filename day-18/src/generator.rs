@@ -0,0 +1,124 @@
+use crate::Day18;
+use aoc_core::{Generator, Rng};
+use aoc_grid::Direction;
+
+/// A dig plan of `4 * size` instructions whose colors hide a second, much larger plan.
+///
+/// Both trenches close and never cross themselves, and every instruction turns. Each is the
+/// outline of a shape that is `size` columns wide with a random top and bottom, turned and
+/// walked in a random direction.
+impl Generator for Day18 {
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let columns = size.max(1);
+        let plain = trench(columns, 10, 200, rng);
+        let hidden = trench(columns, 60_000, 0xfffff, rng);
+
+        plain
+            .into_iter()
+            .zip(hidden)
+            .map(|((dir, amount), (hidden_dir, hidden_amount))| {
+                let letter = match dir {
+                    Direction::Up => 'U',
+                    Direction::Down => 'D',
+                    Direction::Left => 'L',
+                    Direction::Right => 'R',
+                };
+                let digit = match hidden_dir {
+                    Direction::Right => 0,
+                    Direction::Down => 1,
+                    Direction::Left => 2,
+                    Direction::Up => 3,
+                };
+                format!("{letter} {amount} (#{hidden_amount:05x}{digit})")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The outline of `columns` columns side by side, each at most `step` wide and `height` high,
+/// with neighbouring columns overlapping so the outline never touches itself.
+fn trench(columns: usize, step: i64, height: i64, rng: &mut Rng) -> Vec<(Direction, i64)> {
+    let shift = |rng: &mut Rng| match rng.chance(0.5) {
+        true => rng.range(1..=step),
+        false => -rng.range(1..=step),
+    };
+
+    // The bottom and top of each column.
+    let mut spans = vec![(0, rng.range(1..=step))];
+    while spans.len() < columns {
+        let (bottom, top) = spans[spans.len() - 1];
+        let (next_bottom, next_top) = (bottom + shift(rng), top + shift(rng));
+        let overlaps = bottom.max(next_bottom) < top.min(next_top);
+        if overlaps && next_top - next_bottom <= height {
+            spans.push((next_bottom, next_top));
+        }
+    }
+    let widths = spans
+        .iter()
+        .map(|_| rng.range(1..=step))
+        .collect::<Vec<_>>();
+
+    let vertical = |from: i64, to: i64| match from < to {
+        true => (Direction::Up, to - from),
+        false => (Direction::Down, from - to),
+    };
+    let mut commands = vec![(Direction::Up, spans[0].1 - spans[0].0)];
+    for (i, &width) in widths.iter().enumerate() {
+        commands.push((Direction::Right, width));
+        if let Some(next) = spans.get(i + 1) {
+            commands.push(vertical(spans[i].1, next.1));
+        }
+    }
+    let (bottom, top) = spans[spans.len() - 1];
+    commands.push((Direction::Down, top - bottom));
+    for (i, &width) in widths.iter().enumerate().rev() {
+        commands.push((Direction::Left, width));
+        if i > 0 {
+            commands.push(vertical(spans[i].0, spans[i - 1].0));
+        }
+    }
+
+    if rng.chance(0.5) {
+        commands.reverse();
+        commands
+            .iter_mut()
+            .for_each(|(dir, _)| *dir = dir.opposite());
+    }
+    for _ in 0..rng.range(0..=3) {
+        commands
+            .iter_mut()
+            .for_each(|(dir, _)| *dir = dir.turn_right());
+    }
+    commands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::generate;
+    use aoc_grid::IPoint;
+
+    #[test]
+    fn every_instruction_turns() {
+        let mut rng = Rng::new(18);
+        for columns in 1..20 {
+            let commands = trench(columns, 10, 200, &mut rng);
+            assert_eq!(4 * columns, commands.len());
+            let end = commands
+                .iter()
+                .fold(IPoint::ORIGIN, |at, &(dir, amount)| at.step(dir, amount));
+            assert_eq!(IPoint::ORIGIN, end);
+            for (i, &(dir, amount)) in commands.iter().enumerate() {
+                let (next, _) = commands[(i + 1) % commands.len()];
+                assert!(next != dir && next != dir.opposite());
+                assert!(amount > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn generates_four_instructions_per_size() {
+        assert_eq!(1200, generate::<Day18>(300, 18).lines().count());
+    }
+}
@@ -1,4 +1,54 @@
+use crate::custom_error::AocError;
+use crate::part1::Command;
+use aoc_core::{parse_complete, Solution};
+use nom::character::complete::{line_ending, space1};
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use std::fmt::Display;
+
 pub mod custom_error;
 
+#[cfg(feature = "visual")]
+mod export;
+mod generator;
 pub mod part1;
 pub mod part2;
+
+pub struct Day18;
+
+/// Both readings of the dig plan: the plain instructions and the ones hidden in the colors.
+pub struct DigPlan {
+    pub commands: Vec<Command>,
+    pub corrected: Vec<Command>,
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = DigPlan;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let entries = parse_complete(
+            input,
+            separated_list1(
+                line_ending,
+                separated_pair(part1::command, space1, part2::corrected),
+            ),
+        )?;
+        let (commands, corrected) = entries.into_iter().unzip();
+
+        Ok(DigPlan {
+            commands,
+            corrected,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,90 +1,165 @@
 use crate::custom_error::AocError;
+use crate::{Day18, DigPlan};
+use aoc_algo::enclosed_points;
+use aoc_core::{Solution, Span};
+use aoc_grid::{Direction, IPoint};
+use nom::branch::alt;
+use nom::character::complete::{self, char, space1};
+use nom::combinator::{map, value};
+use nom::sequence::separated_pair;
+use nom::IResult;
 
+#[derive(Debug)]
 pub struct Command {
-    dir: Dir,
+    dir: Direction,
     amount: usize,
 }
 
 impl Command {
-    pub fn new(dir: Dir, amount: usize) -> Self {
+    pub fn new(dir: Direction, amount: usize) -> Self {
         Self { dir, amount }
     }
 }
 
-struct Vec2 {
-    x: i64,
-    y: i64,
-}
-
-impl Vec2 {
-    fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
-    }
-
-    fn next(&self, dir: &Dir, amount: usize) -> Self {
-        match dir {
-            Dir::Up => Self::new(self.x, self.y + amount as i64),
-            Dir::Down => Self::new(self.x, self.y - amount as i64),
-            Dir::Left => Self::new(self.x - amount as i64, self.y),
-            Dir::Right => Self::new(self.x + amount as i64, self.y),
-        }
-    }
-}
+/// The plain instruction at the start of a line, e.g. `R 6`.
+pub(crate) fn command(input: Span) -> IResult<Span, Command> {
+    let direction = alt((
+        value(Direction::Up, char('U')),
+        value(Direction::Down, char('D')),
+        value(Direction::Left, char('L')),
+        value(Direction::Right, char('R')),
+    ));
 
-pub enum Dir {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-fn parse(input: &str) -> Vec<Command> {
-    input
-        .lines()
-        .map(|line| {
-            let (c, _) = line.split_once(" (").unwrap();
-            let (_, amount) = c.split_once(' ').unwrap();
-            let dir = match line.chars().next().unwrap() {
-                'U' => Dir::Up,
-                'D' => Dir::Down,
-                'L' => Dir::Left,
-                'R' => Dir::Right,
-                _ => panic!("invalid direction"),
-            };
-            let amount = amount.parse().unwrap();
-            Command { dir, amount }
-        })
-        .collect()
+    map(
+        separated_pair(direction, space1, complete::u32),
+        |(dir, amount)| Command::new(dir, amount as usize),
+    )(input)
 }
 
 pub trait Area {
-    fn area(&self) -> usize;
+    /// The cubic meters the trench and its inside hold, or `None` if that does not fit in a
+    /// `usize`.
+    fn area(&self) -> Option<usize>;
 }
 
 impl Area for Vec<Command> {
-    fn area(&self) -> usize {
-        let (area, perimeter, _) = self.iter().fold(
-            (0, 0, Vec2::new(0, 0)),
-            |(area, perimeter, pos), Command { dir, amount }| {
-                let new = pos.next(dir, *amount);
-                let area = area + (pos.x * new.y) - (pos.y * new.x);
-                let perimeter = perimeter + (pos.x - new.x).abs() + (pos.y - new.y).abs();
-                (area, perimeter, new)
-            },
-        );
-
-        ((area.abs() + perimeter) / 2 + 1) as usize
+    fn area(&self) -> Option<usize> {
+        usize::try_from(enclosed_points(&vertices(self))?).ok()
     }
 }
 
+/// The corners the trench turns at, starting from the one after the first command, as `(x, y)`
+/// relative to where digging started.
+pub(crate) fn vertices(commands: &[Command]) -> Vec<(i64, i64)> {
+    commands
+        .iter()
+        .scan(IPoint::ORIGIN, |pos, Command { dir, amount }| {
+            *pos = pos.step(*dir, *amount as i64);
+            Some((pos.x, pos.y))
+        })
+        .collect()
+}
+
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    Ok(parse(input).area())
+    solve(&Day18::parse(input)?)
+}
+
+#[tracing::instrument(skip(plan))]
+pub fn solve(plan: &DigPlan) -> Result<usize, AocError> {
+    plan.commands.area().ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_grid::{Grid, Point};
+    use proptest::prelude::*;
+
+    /// Digs the trench cell by cell and counts everything the outside cannot reach.
+    fn flood_fill(commands: &[Command]) -> usize {
+        let mut trench = vec![IPoint::ORIGIN];
+        for Command { dir, amount } in commands {
+            for _ in 0..*amount {
+                trench.push(trench[trench.len() - 1].step(*dir, 1));
+            }
+        }
+
+        let min_x = trench.iter().map(|p| p.x).min().unwrap();
+        let min_y = trench.iter().map(|p| p.y).min().unwrap();
+        let max_x = trench.iter().map(|p| p.x).max().unwrap();
+        let max_y = trench.iter().map(|p| p.y).max().unwrap();
+
+        // One empty cell of margin all around keeps the outside connected.
+        let width = (max_x - min_x) as usize + 3;
+        let height = (max_y - min_y) as usize + 3;
+        let mut dug = Grid::new(width, height, false);
+        for p in trench {
+            dug[Point::new((p.x - min_x) as usize + 1, (p.y - min_y) as usize + 1)] = true;
+        }
+
+        let mut outside = Grid::new(width, height, false);
+        let mut queue = vec![Point::new(0, 0)];
+        outside[Point::new(0, 0)] = true;
+        while let Some(point) = queue.pop() {
+            for next in dug.neighbours4(point).collect::<Vec<_>>() {
+                if !dug[next] && !outside[next] {
+                    outside[next] = true;
+                    queue.push(next);
+                }
+            }
+        }
+
+        outside.iter().filter(|&&outside| !outside).count()
+    }
+
+    /// A closed trench that never crosses itself: the outline of a histogram of `columns`,
+    /// turned a number of quarter turns and possibly walked the other way round.
+    fn dig_plan() -> impl Strategy<Value = Vec<Command>> {
+        (
+            prop::collection::vec((1..=8usize, 1..=8usize), 1..=6),
+            0..4usize,
+            any::<bool>(),
+        )
+            .prop_map(|(columns, turns, reversed)| {
+                let vertical = |from: usize, to: usize| match from.cmp(&to) {
+                    std::cmp::Ordering::Less => Some(Command::new(Direction::Up, to - from)),
+                    std::cmp::Ordering::Greater => Some(Command::new(Direction::Down, from - to)),
+                    std::cmp::Ordering::Equal => None,
+                };
+
+                let mut commands = Vec::new();
+                let mut height = 0;
+                for &(width, next) in &columns {
+                    commands.extend(vertical(height, next));
+                    commands.push(Command::new(Direction::Right, width));
+                    height = next;
+                }
+                commands.extend(vertical(height, 0));
+                let total = columns.iter().map(|&(width, _)| width).sum();
+                commands.push(Command::new(Direction::Left, total));
+
+                if reversed {
+                    commands.reverse();
+                    for command in &mut commands {
+                        command.dir = command.dir.opposite();
+                    }
+                }
+                for command in &mut commands {
+                    for _ in 0..turns {
+                        command.dir = command.dir.turn_right();
+                    }
+                }
+                commands
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn shoelace_agrees_with_flood_fill(commands in dig_plan()) {
+            prop_assert_eq!(Some(flood_fill(&commands)), commands.area());
+        }
+    }
 
     #[test]
     fn test_process() -> miette::Result<()> {
@@ -105,4 +180,14 @@ U 2 (#7a21e3)"#;
         assert_eq!(62, process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn measures_lagoons_beyond_64_bit_products() -> miette::Result<()> {
+        let input = "R 4000000000 (#000000)
+D 4000000000 (#000001)
+L 4000000000 (#000002)
+U 4000000000 (#000003)";
+        assert_eq!(16_000_000_008_000_000_001, process(input)?);
+        Ok(())
+    }
 }
@@ -1,29 +1,43 @@
 use crate::custom_error::AocError;
-use crate::part1::{Area, Command, Dir};
+use crate::part1::{Area, Command};
+use crate::{Day18, DigPlan};
+use aoc_core::{Solution, Span};
+use aoc_grid::Direction;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while_m_n};
+use nom::character::complete::char;
+use nom::combinator::{map, map_res, value};
+use nom::sequence::{delimited, pair};
+use nom::IResult;
 
-fn parse(input: &str) -> Vec<Command> {
-    input
-        .lines()
-        .map(|line| {
-            let line = line.strip_suffix(')').unwrap();
-            let (_, hex) = line.split_once("(#").unwrap();
-            let (amount, dir) = hex.split_at(5);
-            let amount = usize::from_str_radix(amount, 16).unwrap();
-            let dir = match dir {
-                "3" => Dir::Up,
-                "1" => Dir::Down,
-                "2" => Dir::Left,
-                "0" => Dir::Right,
-                _ => panic!("invalid direction"),
-            };
-            Command::new(dir, amount)
-        })
-        .collect()
+/// The instruction hidden in the color, e.g. `(#70c710)`: five hex digits of distance, then
+/// the direction.
+pub(crate) fn corrected(input: Span) -> IResult<Span, Command> {
+    let amount = map_res(
+        take_while_m_n(5, 5, |c: char| c.is_ascii_hexdigit()),
+        |hex: Span| usize::from_str_radix(hex.fragment(), 16),
+    );
+    let direction = alt((
+        value(Direction::Right, char('0')),
+        value(Direction::Down, char('1')),
+        value(Direction::Left, char('2')),
+        value(Direction::Up, char('3')),
+    ));
+
+    map(
+        delimited(tag("(#"), pair(amount, direction), char(')')),
+        |(amount, dir)| Command::new(dir, amount),
+    )(input)
 }
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    Ok(parse(input).area())
+    solve(&Day18::parse(input)?)
+}
+
+#[tracing::instrument(skip(plan))]
+pub fn solve(plan: &DigPlan) -> Result<usize, AocError> {
+    plan.corrected.area().ok_or(AocError::Overflow)
 }
 
 #[cfg(test)]